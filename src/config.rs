@@ -0,0 +1,129 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fmt;
+use std::sync::Arc;
+
+const DEFAULT_API_BASE: &str = "https://api.stripe.com/v1";
+
+/// An error returned by the Stripe API, or encountered while talking to it.
+#[derive(Debug)]
+pub struct Error {
+    message: String,
+}
+
+impl Error {
+    fn new(message: impl Into<String>) -> Self {
+        Error { message: message.into() }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// The result type returned by every Stripe API call.
+pub type Response<T> = Result<T, Error>;
+
+#[derive(Debug)]
+struct ClientConfig {
+    secret_key: String,
+    api_base: String,
+}
+
+/// A client for making requests against the Stripe API, scoped to a single secret key.
+///
+/// Cloning a `Client` is cheap; the underlying configuration is shared.
+#[derive(Clone, Debug)]
+pub struct Client {
+    config: Arc<ClientConfig>,
+    idempotency_key: Option<String>,
+}
+
+impl Client {
+    /// Creates a new client using the given secret key.
+    pub fn new(secret_key: impl Into<String>) -> Self {
+        Client {
+            config: Arc::new(ClientConfig {
+                secret_key: secret_key.into(),
+                api_base: DEFAULT_API_BASE.into(),
+            }),
+            idempotency_key: None,
+        }
+    }
+
+    /// Returns a copy of this client that sends the given `Idempotency-Key` header with
+    /// every request it makes (of any method), so retrying the exact same request (e.g.
+    /// after a network timeout) is safe to do without creating a duplicate object.
+    pub fn with_idempotency_key(&self, idempotency_key: impl Into<String>) -> Self {
+        Client { config: Arc::clone(&self.config), idempotency_key: Some(idempotency_key.into()) }
+    }
+
+    /// Issues a `GET` request with no query parameters.
+    pub fn get<T: DeserializeOwned>(&self, path: &str) -> Response<T> {
+        self.execute(Method::Get, path, None)
+    }
+
+    /// Issues a `GET` request, serializing `params` onto the query string.
+    pub fn get_query<T: DeserializeOwned>(&self, path: &str, params: &impl Serialize) -> Response<T> {
+        let query = serde_urlencoded::to_string(params).map_err(|e| Error::new(e.to_string()))?;
+        let path = if query.is_empty() { path.to_string() } else { format!("{}?{}", path, query) };
+        self.execute(Method::Get, &path, None)
+    }
+
+    /// Issues a `POST` request with an empty body.
+    pub fn post<T: DeserializeOwned>(&self, path: &str) -> Response<T> {
+        self.execute(Method::Post, path, None)
+    }
+
+    /// Issues a `POST` request, serializing `params` as a `application/x-www-form-urlencoded` body.
+    pub fn post_form<T: DeserializeOwned>(&self, path: &str, params: &impl Serialize) -> Response<T> {
+        let body = serde_urlencoded::to_string(params).map_err(|e| Error::new(e.to_string()))?;
+        self.execute(Method::Post, path, Some(body))
+    }
+
+    /// Issues a `DELETE` request with no query parameters.
+    pub fn delete<T: DeserializeOwned>(&self, path: &str) -> Response<T> {
+        self.execute(Method::Delete, path, None)
+    }
+
+    /// Issues a `DELETE` request, serializing `params` onto the query string.
+    pub fn delete_query<T: DeserializeOwned>(&self, path: &str, params: &impl Serialize) -> Response<T> {
+        let query = serde_urlencoded::to_string(params).map_err(|e| Error::new(e.to_string()))?;
+        let path = if query.is_empty() { path.to_string() } else { format!("{}?{}", path, query) };
+        self.execute(Method::Delete, &path, None)
+    }
+
+    fn execute<T: DeserializeOwned>(&self, method: Method, path: &str, body: Option<String>) -> Response<T> {
+        let url = format!("{}{}", self.config.api_base, path);
+        let mut request = match method {
+            Method::Get => ureq::get(&url),
+            Method::Post => ureq::post(&url),
+            Method::Delete => ureq::delete(&url),
+        };
+
+        request = request.set("Authorization", &format!("Bearer {}", self.config.secret_key));
+        if let Some(idempotency_key) = &self.idempotency_key {
+            request = request.set("Idempotency-Key", idempotency_key);
+        }
+
+        let response = match body {
+            Some(body) => request
+                .set("Content-Type", "application/x-www-form-urlencoded")
+                .send_string(&body),
+            None => request.call(),
+        }
+        .map_err(|e| Error::new(e.to_string()))?;
+
+        response.into_json().map_err(|e| Error::new(e.to_string()))
+    }
+}
+
+enum Method {
+    Get,
+    Post,
+    Delete,
+}
@@ -40,6 +40,8 @@ mod scheduled_query;
 mod sku;
 mod source;
 mod subscription;
+mod subscription_schedule;
+mod test_clock;
 mod topup;
 mod transaction;
 mod transfer;
@@ -89,6 +91,8 @@ pub use self::scheduled_query::*;
 pub use self::sku::*;
 pub use self::source::*;
 pub use self::subscription::*;
+pub use self::subscription_schedule::*;
+pub use self::test_clock::*;
 pub use self::topup::*;
 pub use self::transaction::*;
 pub use self::transfer::*;
@@ -1,67 +1,284 @@
-use error::Error;
-use http;
-use resources::{Address, CardParams, Deleted, Discount, Source, Subscription};
-use params::{List, Metadata};
+// ======================================
+// This file was automatically generated.
+// ======================================
 
-#[derive(Debug, Deserialize, Serialize)]
+use crate::config::{Client, Response};
+use crate::ids::CustomerId;
+use crate::params::{Deleted, Expand, Expandable, List, Metadata, Object, RangeQuery, Timestamp};
+use crate::resources::{Address, CardParams, Discount, PaymentSource, Subscription};
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct CustomerShippingDetails {
     pub address: Address,
     pub name: String,
     pub phone: String,
 }
 
-#[derive(Serialize)]
+#[derive(Clone, Debug, Serialize)]
 #[serde(untagged)]
 pub enum CustomerSource<'a> {
     Token(&'a str),
     Card(CardParams<'a>),
 }
 
-#[derive(Default, Serialize)]
-pub struct CustomerParams<'a> {
-    #[serde(skip_serializing_if = "Option::is_none")] pub account_balance: Option<i64>,
-    #[serde(skip_serializing_if = "Option::is_none")] pub business_vat_id: Option<&'a str>,
-    #[serde(skip_serializing_if = "Option::is_none")] pub coupon: Option<&'a str>,
-    #[serde(skip_serializing_if = "Option::is_none")] pub description: Option<&'a str>,
-    #[serde(skip_serializing_if = "Option::is_none")] pub email: Option<&'a str>,
-    #[serde(skip_serializing_if = "Option::is_none")] pub metadata: Option<Metadata>,
-    #[serde(skip_serializing_if = "Option::is_none")] pub shipping: Option<CustomerShippingDetails>,
-    #[serde(skip_serializing_if = "Option::is_none")] pub source: Option<CustomerSource<'a>>,
-}
-
-#[derive(Debug, Deserialize)]
+/// The resource representing a Stripe "Customer".
+///
+/// For more details see [https://stripe.com/docs/api/customers/object](https://stripe.com/docs/api/customers/object).
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Customer {
-    pub id: String,
+    /// Unique identifier for the object.
+    pub id: CustomerId,
+
+    /// Current balance, if any, being stored on the customer.
+    ///
+    /// If negative, the customer has credit to apply to their next invoice.
+    /// If positive, the customer has an amount owed that will be added to their next invoice.
     pub account_balance: i64,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub business_vat_id: Option<String>,
-    pub created: u64,
-    pub currency: String,
-    pub default_source: String,
+
+    /// Time at which the object was created.
+    ///
+    /// Measured in seconds since the Unix epoch.
+    pub created: Timestamp,
+
+    /// Three-letter ISO currency code, in lowercase.
+    ///
+    /// Must be a supported currency.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+
+    /// ID of the default payment source for the customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_source: Option<Expandable<PaymentSource>>,
+
+    /// Whether the customer has been delinquent.
     pub delinquent: bool,
-    pub desc: Option<String>,
-    pub discount: Option<Discount>,
-    pub email: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Describes the current discount active on the customer, if there is one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discount: Option<Expandable<Discount>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+
+    /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
     pub livemode: bool,
+
+    /// Set of key-value pairs that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
     pub metadata: Metadata,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub shipping: Option<CustomerShippingDetails>,
-    pub sources: List<Source>,
+
+    /// The customer's payment sources, if any.
+    pub sources: List<PaymentSource>,
+
+    /// The customer's current subscriptions, if any.
     pub subscriptions: List<Subscription>,
 }
 
 impl Customer {
-    pub fn create(params: CustomerParams, key: &str) -> Result<Customer, Error> {
-        return http::post("/customers", key, params);
+    /// Returns a list of your customers.
+    pub fn list(client: &Client, params: ListCustomers<'_>) -> Response<List<Customer>> {
+        client.get_query("/customers", &params)
+    }
+
+    /// Creates a new customer object.
+    pub fn create(client: &Client, params: CreateCustomer<'_>) -> Response<Customer> {
+        client.post_form("/customers", &params)
+    }
+
+    /// Creates a new customer object, tagged with an idempotency key so that retrying this
+    /// exact request (e.g. after a network timeout) returns the original customer instead of
+    /// creating a duplicate.
+    pub fn create_with_idempotency_key(
+        client: &Client,
+        params: CreateCustomer<'_>,
+        idempotency_key: &str,
+    ) -> Response<Customer> {
+        client.with_idempotency_key(idempotency_key).post_form("/customers", &params)
+    }
+
+    /// Retrieves the details of an existing customer.
+    pub fn retrieve(client: &Client, id: &CustomerId, expand: &[&str]) -> Response<Customer> {
+        client.get_query(&format!("/customers/{}", id), &Expand { expand })
+    }
+
+    /// Updates the specified customer by setting the values of the parameters passed.
+    ///
+    /// Any parameters not provided will be left unchanged.
+    pub fn update(
+        client: &Client,
+        id: &CustomerId,
+        params: UpdateCustomer<'_>,
+    ) -> Response<Customer> {
+        client.post_form(&format!("/customers/{}", id), &params)
+    }
+
+    /// Permanently deletes a customer.
+    ///
+    /// It cannot be undone.
+    pub fn delete(client: &Client, id: &CustomerId) -> Response<Deleted<CustomerId>> {
+        client.delete(&format!("/customers/{}", id))
+    }
+}
+
+impl Object for Customer {
+    type Id = CustomerId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
     }
+    fn object(&self) -> &'static str {
+        "customer"
+    }
+}
 
-    pub fn get(customer_id: &str, key: &str) -> Result<Customer, Error> {
-        return http::get(&format!("/customers/{}", customer_id), key);
+/// The parameters for `Customer::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateCustomer<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_balance: Option<i64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_vat_id: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coupon: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<&'a str>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping: Option<CustomerShippingDetails>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<CustomerSource<'a>>,
+}
+
+impl<'a> CreateCustomer<'a> {
+    pub fn new() -> Self {
+        CreateCustomer {
+            account_balance: Default::default(),
+            business_vat_id: Default::default(),
+            coupon: Default::default(),
+            description: Default::default(),
+            email: Default::default(),
+            expand: Default::default(),
+            metadata: Default::default(),
+            shipping: Default::default(),
+            source: Default::default(),
+        }
     }
+}
+
+/// The parameters for `Customer::update`.
+#[derive(Clone, Debug, Serialize)]
+pub struct UpdateCustomer<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_balance: Option<i64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_vat_id: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coupon: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_source: Option<&'a str>,
 
-    pub fn update(customer_id: &str, params: CustomerParams, key: &str) -> Result<Customer, Error> {
-        return http::post(&format!("/customers/{}", customer_id), key, params);
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<&'a str>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping: Option<CustomerShippingDetails>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<CustomerSource<'a>>,
+}
+
+impl<'a> UpdateCustomer<'a> {
+    pub fn new() -> Self {
+        UpdateCustomer {
+            account_balance: Default::default(),
+            business_vat_id: Default::default(),
+            coupon: Default::default(),
+            default_source: Default::default(),
+            description: Default::default(),
+            email: Default::default(),
+            expand: Default::default(),
+            metadata: Default::default(),
+            shipping: Default::default(),
+            source: Default::default(),
+        }
     }
+}
+
+/// The parameters for `Customer::list`.
+#[derive(Clone, Debug, Serialize)]
+pub struct ListCustomers<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+
+    /// A filter on the list based on the customer's `email` field.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<&'a str>,
+
+    /// A cursor for use in pagination.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a CustomerId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a CustomerId>,
+}
 
-    pub fn delete(customer_id: &str, key: &str) -> Result<Deleted, Error> {
-        return http::delete(&format!("/customers/{}", customer_id), key);
+impl<'a> ListCustomers<'a> {
+    pub fn new() -> Self {
+        ListCustomers {
+            created: Default::default(),
+            email: Default::default(),
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            starting_after: Default::default(),
+        }
     }
 }
@@ -0,0 +1,373 @@
+// ======================================
+// This file was automatically generated.
+// ======================================
+
+use crate::config::{Client, Response};
+use crate::ids::{IssuingCardId, IssuingCardholderId};
+use crate::params::{Expand, Expandable, List, Metadata, Object, RangeQuery, Timestamp};
+use crate::resources::{Currency, IssuingCardholder, SpendingControls};
+use serde_derive::{Deserialize, Serialize};
+
+/// The resource representing a Stripe "IssuingCard".
+///
+/// For more details see [https://stripe.com/docs/api/issuing/cards/object](https://stripe.com/docs/api/issuing/cards/object).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct IssuingCard {
+    /// Unique identifier for the object.
+    pub id: IssuingCardId,
+
+    /// The brand of the card.
+    pub brand: String,
+
+    /// The reason why the card was canceled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancellation_reason: Option<IssuingCardCancellationReason>,
+
+    /// The Cardholder object to which the card belongs.
+    pub cardholder: Expandable<IssuingCardholder>,
+
+    /// Time at which the object was created.
+    ///
+    /// Measured in seconds since the Unix epoch.
+    pub created: Timestamp,
+
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    pub currency: Currency,
+
+    /// The expiration month of the card.
+    pub exp_month: i64,
+
+    /// The expiration year of the card.
+    pub exp_year: i64,
+
+    /// The last 4 digits of the card number.
+    pub last4: String,
+
+    /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+    pub livemode: bool,
+
+    /// Set of key-value pairs that you can attach to an object.
+    pub metadata: Metadata,
+
+    /// The card this card replaces, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replacement_for: Option<Expandable<IssuingCard>>,
+
+    /// The reason why the previous card needed to be replaced.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replacement_reason: Option<IssuingCardReplacementReason>,
+
+    /// Where and how the card will be shipped.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping: Option<Shipping>,
+
+    /// Rules that control spending across this card and its cardholder.
+    pub spending_controls: SpendingControls,
+
+    /// Whether authorizations can be approved on this card.
+    pub status: IssuingCardStatus,
+
+    /// The type of the card.
+    #[serde(rename = "type")]
+    pub type_: IssuingCardType,
+}
+
+impl IssuingCard {
+    /// Returns a list of Issuing `Card` objects.
+    ///
+    /// The objects are sorted in descending order by creation date, with the most recently created object appearing first.
+    pub fn list(client: &Client, params: ListIssuingCards<'_>) -> Response<List<IssuingCard>> {
+        client.get_query("/issuing/cards", &params)
+    }
+
+    /// Creates an Issuing `Card` object.
+    pub fn create(client: &Client, params: CreateIssuingCard<'_>) -> Response<IssuingCard> {
+        client.post_form("/issuing/cards", &params)
+    }
+
+    /// Retrieves an Issuing `Card` object.
+    pub fn retrieve(client: &Client, id: &IssuingCardId, expand: &[&str]) -> Response<IssuingCard> {
+        client.get_query(&format!("/issuing/cards/{}", id), &Expand { expand })
+    }
+
+    /// Updates the specified Issuing `Card` object by setting the values of the parameters passed.
+    ///
+    /// Any parameters not provided will be left unchanged.
+    pub fn update(
+        client: &Client,
+        id: &IssuingCardId,
+        params: UpdateIssuingCard<'_>,
+    ) -> Response<IssuingCard> {
+        client.post_form(&format!("/issuing/cards/{}", id), &params)
+    }
+
+    /// Updates the shipping status of the specified test-mode Issuing `Card` object to `shipped`.
+    pub fn ship(client: &Client, id: &IssuingCardId) -> Response<IssuingCard> {
+        client.post(&format!("/test_helpers/issuing/cards/{}/shipping/ship", id))
+    }
+
+    /// Updates the shipping status of the specified test-mode Issuing `Card` object to `delivered`.
+    pub fn deliver(client: &Client, id: &IssuingCardId) -> Response<IssuingCard> {
+        client.post(&format!("/test_helpers/issuing/cards/{}/shipping/deliver", id))
+    }
+
+    /// Updates the shipping status of the specified test-mode Issuing `Card` object to `returned`.
+    pub fn return_card(client: &Client, id: &IssuingCardId) -> Response<IssuingCard> {
+        client.post(&format!("/test_helpers/issuing/cards/{}/shipping/return", id))
+    }
+
+    /// Updates the shipping status of the specified test-mode Issuing `Card` object to `failure`.
+    pub fn fail(client: &Client, id: &IssuingCardId) -> Response<IssuingCard> {
+        client.post(&format!("/test_helpers/issuing/cards/{}/shipping/fail", id))
+    }
+}
+
+impl Object for IssuingCard {
+    type Id = IssuingCardId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "issuing.card"
+    }
+}
+
+/// The resource representing a Stripe "IssuingCardShipping".
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Shipping {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<crate::resources::Address>,
+
+    /// The delivery company that shipped the card.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub carrier: Option<String>,
+
+    /// Additional information that may be required for customs to clear the shipment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customs: Option<ShippingCustoms>,
+
+    /// A unix timestamp representing a best estimate of when the card will be delivered.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eta: Option<Timestamp>,
+
+    /// Recipient name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Shipment service, such as `standard` or `express`.
+    pub service: ShippingServiceType,
+
+    /// The delivery status of the card.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<ShippingStatus>,
+
+    /// A tracking number for a card shipment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tracking_number: Option<String>,
+
+    /// A link to the shipping carrier's site where the card shipment can be tracked.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tracking_url: Option<String>,
+
+    /// Packaging options.
+    #[serde(rename = "type")]
+    pub type_: ShippingType,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ShippingCustoms {
+    /// A registration number used for customs in Europe.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eori_number: Option<String>,
+}
+
+/// An enum representing the possible values of a `Shipping`'s `service` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ShippingServiceType {
+    Express,
+    Priority,
+    Standard,
+}
+
+impl std::default::Default for ShippingServiceType {
+    fn default() -> Self {
+        Self::Standard
+    }
+}
+
+/// An enum representing the possible values of a `Shipping`'s `status` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ShippingStatus {
+    Canceled,
+    Delivered,
+    Failure,
+    Pending,
+    Returned,
+    Shipped,
+}
+
+/// An enum representing the possible values of a `Shipping`'s `type` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ShippingType {
+    Bulk,
+    Individual,
+}
+
+impl std::default::Default for ShippingType {
+    fn default() -> Self {
+        Self::Individual
+    }
+}
+
+/// An enum representing the possible values of an `IssuingCard`'s `cancellation_reason` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum IssuingCardCancellationReason {
+    DesignRejected,
+    Lost,
+    Stolen,
+}
+
+/// An enum representing the possible values of an `IssuingCard`'s `replacement_reason` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum IssuingCardReplacementReason {
+    Damaged,
+    Expired,
+    Lost,
+    Stolen,
+}
+
+/// An enum representing the possible values of an `IssuingCard`'s `status` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum IssuingCardStatus {
+    Active,
+    Canceled,
+    Inactive,
+}
+
+/// An enum representing the possible values of an `IssuingCard`'s `type` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum IssuingCardType {
+    Physical,
+    Virtual,
+}
+
+/// The parameters for `IssuingCard::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateIssuingCard<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cardholder: Option<IssuingCardholderId>,
+
+    pub currency: Currency,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replacement_for: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replacement_reason: Option<IssuingCardReplacementReason>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping: Option<Shipping>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spending_controls: Option<SpendingControls>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<IssuingCardStatus>,
+
+    #[serde(rename = "type")]
+    pub type_: IssuingCardType,
+}
+
+impl<'a> CreateIssuingCard<'a> {
+    pub fn new(currency: Currency, type_: IssuingCardType) -> Self {
+        CreateIssuingCard {
+            cardholder: Default::default(),
+            currency,
+            expand: Default::default(),
+            metadata: Default::default(),
+            replacement_for: Default::default(),
+            replacement_reason: Default::default(),
+            shipping: Default::default(),
+            spending_controls: Default::default(),
+            status: Default::default(),
+            type_,
+        }
+    }
+}
+
+/// The parameters for `IssuingCard::update`.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct UpdateIssuingCard<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancellation_reason: Option<IssuingCardCancellationReason>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spending_controls: Option<SpendingControls>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<IssuingCardStatus>,
+}
+
+impl<'a> UpdateIssuingCard<'a> {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+/// The parameters for `IssuingCard::list`.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ListIssuingCards<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cardholder: Option<IssuingCardholderId>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+
+    /// A cursor for use in pagination.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a IssuingCardId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a IssuingCardId>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<IssuingCardStatus>,
+
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_: Option<IssuingCardType>,
+}
+
+impl<'a> ListIssuingCards<'a> {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
@@ -0,0 +1,358 @@
+// ======================================
+// This file was automatically generated.
+// ======================================
+
+use crate::config::{Client, Response};
+use crate::ids::IssuingCardholderId;
+use crate::params::{Expand, Expandable, List, Metadata, Object, RangeQuery, Timestamp};
+use crate::resources::{Address, File, SpendingControls};
+use serde_derive::{Deserialize, Serialize};
+
+/// The resource representing a Stripe "IssuingCardholder".
+///
+/// For more details see [https://stripe.com/docs/api/issuing/cardholders/object](https://stripe.com/docs/api/issuing/cardholders/object).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct IssuingCardholder {
+    /// Unique identifier for the object.
+    pub id: IssuingCardholderId,
+
+    pub billing: CardholderBilling,
+
+    /// Additional information about a `company` cardholder.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub company: Option<CardholderCompany>,
+
+    /// Time at which the object was created.
+    ///
+    /// Measured in seconds since the Unix epoch.
+    pub created: Timestamp,
+
+    /// The cardholder's email address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+
+    /// Additional information about an `individual` cardholder.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub individual: Option<CardholderIndividual>,
+
+    /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+    pub livemode: bool,
+
+    /// Set of key-value pairs that you can attach to an object.
+    pub metadata: Metadata,
+
+    /// The cardholder's name.
+    ///
+    /// This will be printed on cards issued to them.
+    pub name: String,
+
+    /// The cardholder's phone number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<String>,
+
+    /// Information about verification requirements for the cardholder, including what information needs to be collected.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requirements: Option<CardholderRequirements>,
+
+    /// Rules that control spending across this cardholder and their cards.
+    pub spending_controls: SpendingControls,
+
+    /// Specifies whether to permit authorizations on this cardholder's cards.
+    pub status: CardholderStatus,
+
+    /// One of `individual` or `company`.
+    #[serde(rename = "type")]
+    pub type_: CardholderType,
+}
+
+impl IssuingCardholder {
+    /// Returns a list of Issuing `Cardholder` objects.
+    pub fn list(
+        client: &Client,
+        params: ListIssuingCardholders<'_>,
+    ) -> Response<List<IssuingCardholder>> {
+        client.get_query("/issuing/cardholders", &params)
+    }
+
+    /// Creates a new Issuing `Cardholder` object.
+    pub fn create(
+        client: &Client,
+        params: CreateIssuingCardholder<'_>,
+    ) -> Response<IssuingCardholder> {
+        client.post_form("/issuing/cardholders", &params)
+    }
+
+    /// Retrieves an Issuing `Cardholder` object.
+    pub fn retrieve(
+        client: &Client,
+        id: &IssuingCardholderId,
+        expand: &[&str],
+    ) -> Response<IssuingCardholder> {
+        client.get_query(&format!("/issuing/cardholders/{}", id), &Expand { expand })
+    }
+
+    /// Updates the specified Issuing `Cardholder` object by setting the values of the parameters passed.
+    ///
+    /// Any parameters not provided will be left unchanged.
+    pub fn update(
+        client: &Client,
+        id: &IssuingCardholderId,
+        params: UpdateIssuingCardholder<'_>,
+    ) -> Response<IssuingCardholder> {
+        client.post_form(&format!("/issuing/cardholders/{}", id), &params)
+    }
+}
+
+impl Object for IssuingCardholder {
+    type Id = IssuingCardholderId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "issuing.cardholder"
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CardholderBilling {
+    pub address: Address,
+}
+
+/// Additional information about a `company` cardholder.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CardholderCompany {
+    /// The company's tax ID.
+    ///
+    /// Write-only, never returned on retrieval.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_id: Option<String>,
+
+    /// Whether the company's business ID number was provided.
+    pub tax_id_provided: bool,
+}
+
+/// Additional information about an `individual` cardholder.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CardholderIndividual {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dob: Option<CardholderIndividualDob>,
+
+    /// The first name of this cardholder.
+    pub first_name: String,
+
+    /// The last name of this cardholder.
+    pub last_name: String,
+
+    /// Government-issued ID document for this cardholder.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verification: Option<CardholderIndividualVerification>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CardholderIndividualDob {
+    /// The day of birth, between 1 and 31.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub day: Option<i64>,
+
+    /// The month of birth, between 1 and 12.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub month: Option<i64>,
+
+    /// The four-digit year of birth.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub year: Option<i64>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CardholderIndividualVerification {
+    /// An identifying document, either a passport or local ID card.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub document: Option<CardholderIdDocument>,
+
+    /// An additional identifying document, if the ID document type is `additional`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_document: Option<CardholderIdDocument>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CardholderIdDocument {
+    /// The back of a document returned by a [file upload](https://stripe.com/docs/api#create_file) with a `purpose` value of `identity_document`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub back: Option<Expandable<File>>,
+
+    /// The front of a document returned by a [file upload](https://stripe.com/docs/api#create_file) with a `purpose` value of `identity_document`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub front: Option<Expandable<File>>,
+}
+
+/// Information about verification requirements for the cardholder, including what information needs to be collected.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CardholderRequirements {
+    /// If `disabled_reason` is present, all cards will decline authorizations with `cardholder_verification_required` reason.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disabled_reason: Option<String>,
+
+    /// Array of fields that need to be collected in order to verify and re-enable the cardholder.
+    pub past_due: Vec<String>,
+}
+
+/// An enum representing the possible values of an `IssuingCardholder`'s `status` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CardholderStatus {
+    Active,
+    Blocked,
+    Inactive,
+}
+
+impl std::default::Default for CardholderStatus {
+    fn default() -> Self {
+        Self::Active
+    }
+}
+
+/// An enum representing the possible values of an `IssuingCardholder`'s `type` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CardholderType {
+    Company,
+    Individual,
+}
+
+impl std::default::Default for CardholderType {
+    fn default() -> Self {
+        Self::Individual
+    }
+}
+
+/// The parameters for `IssuingCardholder::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateIssuingCardholder<'a> {
+    pub billing: CardholderBilling,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub company: Option<CardholderCompany>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<&'a str>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub individual: Option<CardholderIndividual>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    pub name: &'a str,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spending_controls: Option<SpendingControls>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<CardholderStatus>,
+
+    #[serde(rename = "type")]
+    pub type_: CardholderType,
+}
+
+impl<'a> CreateIssuingCardholder<'a> {
+    pub fn new(billing: CardholderBilling, name: &'a str, type_: CardholderType) -> Self {
+        CreateIssuingCardholder {
+            billing,
+            company: Default::default(),
+            email: Default::default(),
+            expand: Default::default(),
+            individual: Default::default(),
+            metadata: Default::default(),
+            name,
+            phone_number: Default::default(),
+            spending_controls: Default::default(),
+            status: Default::default(),
+            type_,
+        }
+    }
+}
+
+/// The parameters for `IssuingCardholder::update`.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct UpdateIssuingCardholder<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing: Option<CardholderBilling>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub company: Option<CardholderCompany>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<&'a str>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub individual: Option<CardholderIndividual>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spending_controls: Option<SpendingControls>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<CardholderStatus>,
+}
+
+impl<'a> UpdateIssuingCardholder<'a> {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+/// The parameters for `IssuingCardholder::list`.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ListIssuingCardholders<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<&'a str>,
+
+    /// A cursor for use in pagination.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a IssuingCardholderId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a IssuingCardholderId>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<CardholderStatus>,
+
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_: Option<CardholderType>,
+}
+
+impl<'a> ListIssuingCardholders<'a> {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
@@ -1,4 +1,5 @@
 use serde_derive::{Deserialize, Serialize};
+use std::hash::{Hash, Hasher};
 
 /// The resource representing a Stripe "IssuingAuthorizationMerchantData".
 #[derive(Clone, Debug, Deserialize, Default, Serialize)]
@@ -32,6 +33,60 @@ pub struct MerchantData {
     pub postal_code: Option<String>,
 }
 
+impl MerchantData {
+    /// Returns a cleaned copy of this `MerchantData` with `name`/`city` trimmed and
+    /// title-cased and `state`/`country` trimmed and upper-cased.
+    ///
+    /// `state`/`country` are *not* mapped to their ISO-3166 codes: Stripe already returns
+    /// these as short codes (e.g. `"CA"`, `"US"`) in practice, so this only normalizes
+    /// casing/whitespace. A `state`/`country` that is already a full name (e.g.
+    /// `"United States"`) is upper-cased as-is, not reduced to `"US"`.
+    ///
+    /// This is opt-in: the raw fields as returned by Stripe are left untouched unless
+    /// this method is called. Use [`MerchantData::category_display_name`] for a
+    /// best-effort label for `category`.
+    pub fn normalized(&self) -> MerchantData {
+        MerchantData {
+            network_id: self.network_id.clone(),
+            category: self.category,
+            name: self.name.as_deref().map(title_case),
+            city: self.city.as_deref().map(title_case),
+            state: self.state.as_deref().map(|s| s.trim().to_uppercase()),
+            country: self.country.as_deref().map(|s| s.trim().to_uppercase()),
+            postal_code: self.postal_code.clone(),
+        }
+    }
+
+    /// Returns a best-effort human-readable label for this merchant's `category`.
+    pub fn category_display_name(&self) -> &'static str {
+        self.category.display_name()
+    }
+
+    /// Returns a stable key for deduplicating merchants across transactions, derived
+    /// from `network_id` and the normalized `name`.
+    pub fn merchant_key(&self) -> u64 {
+        let normalized = self.normalized();
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        normalized.network_id.hash(&mut hasher);
+        normalized.name.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+fn title_case(s: &str) -> String {
+    s.trim()
+        .split_whitespace()
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().collect::<String>() + &chars.as_str().to_lowercase(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 /// An enum representing the industry of a merchant.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -329,3 +384,998 @@ impl std::default::Default for MerchantCategory {
         Self::Miscellaneous
     }
 }
+
+/// Limit spending with amount-based rules that apply across this object and the card brand's [merchant category codes](https://stripe.com/docs/issuing/merchant-categories).
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SpendingControls {
+    /// Array of strings containing [categories](https://stripe.com/docs/api#issuing_merchant_category_codes) of authorizations permitted on this card.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_categories: Option<Vec<MerchantCategory>>,
+
+    /// Array of strings containing [categories](https://stripe.com/docs/api#issuing_merchant_category_codes) of authorizations to always decline on this card.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocked_categories: Option<Vec<MerchantCategory>>,
+
+    /// Limit spending with amount-based rules that apply across any cards this object controls.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spending_limits: Option<Vec<SpendingControlsSpendingLimit>>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SpendingControlsSpendingLimit {
+    /// Maximum amount allowed to spend per interval.
+    pub amount: i64,
+
+    /// Array of strings containing [categories](https://stripe.com/docs/api#issuing_merchant_category_codes) this limit applies to.
+    ///
+    /// Omitting this field will apply the limit to all categories.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub categories: Option<Vec<MerchantCategory>>,
+
+    /// Interval (or event) to which the amount applies.
+    pub interval: SpendingControlsSpendingLimitInterval,
+}
+
+/// An enum representing the possible values of an `SpendingControlsSpendingLimit`'s `interval` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SpendingControlsSpendingLimitInterval {
+    AllTime,
+    Daily,
+    Monthly,
+    PerAuthorization,
+    Weekly,
+    Yearly,
+}
+
+impl SpendingControlsSpendingLimitInterval {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SpendingControlsSpendingLimitInterval::AllTime => "all_time",
+            SpendingControlsSpendingLimitInterval::Daily => "daily",
+            SpendingControlsSpendingLimitInterval::Monthly => "monthly",
+            SpendingControlsSpendingLimitInterval::PerAuthorization => "per_authorization",
+            SpendingControlsSpendingLimitInterval::Weekly => "weekly",
+            SpendingControlsSpendingLimitInterval::Yearly => "yearly",
+        }
+    }
+}
+
+impl AsRef<str> for SpendingControlsSpendingLimitInterval {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for SpendingControlsSpendingLimitInterval {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl std::default::Default for SpendingControlsSpendingLimitInterval {
+    fn default() -> Self {
+        Self::AllTime
+    }
+}
+
+impl MerchantCategory {
+    /// The four-digit [merchant category code](https://stripe.com/docs/issuing/merchant-categories) (MCC) that card networks use to classify this category.
+    pub fn mcc(&self) -> &'static str {
+        match self {
+            MerchantCategory::AcRefrigerationRepair => "7623",
+            MerchantCategory::AccountingBookkeepingServices => "8931",
+            MerchantCategory::AdvertisingServices => "7311",
+            MerchantCategory::AgriculturalCooperative => "0763",
+            MerchantCategory::AirlinesAirCarriers => "4511",
+            MerchantCategory::AirportsFlyingFields => "4582",
+            MerchantCategory::AmbulanceServices => "4119",
+            MerchantCategory::AmusementParksCarnivals => "7996",
+            MerchantCategory::AntiqueReproductions => "5937",
+            MerchantCategory::AntiqueShops => "5932",
+            MerchantCategory::Aquariums => "7998",
+            MerchantCategory::ArchitecturalSurveyingServices => "8911",
+            MerchantCategory::ArtDealersAndGalleries => "5971",
+            MerchantCategory::ArtistsSupplyAndCraftShops => "5970",
+            MerchantCategory::AutoAndHomeSupplyStores => "5531",
+            MerchantCategory::AutoBodyRepairShops => "7531",
+            MerchantCategory::AutoPaintShops => "7535",
+            MerchantCategory::AutoServiceShops => "7538",
+            MerchantCategory::AutomatedCashDisburse => "6011",
+            MerchantCategory::AutomatedFuelDispensers => "5542",
+            MerchantCategory::AutomobileAssociations => "8675",
+            MerchantCategory::AutomotivePartsAndAccessoriesStores => "5533",
+            MerchantCategory::AutomotiveTireStores => "5532",
+            MerchantCategory::BailAndBondPayments => "9223",
+            MerchantCategory::Bakeries => "5462",
+            MerchantCategory::BandsOrchestras => "7929",
+            MerchantCategory::BarberAndBeautyShops => "7230",
+            MerchantCategory::BettingCasinoGambling => "7995",
+            MerchantCategory::BicycleShops => "5940",
+            MerchantCategory::BilliardPoolEstablishments => "7932",
+            MerchantCategory::BoatDealers => "5551",
+            MerchantCategory::BoatRentalsAndLeases => "4457",
+            MerchantCategory::BookStores => "5942",
+            MerchantCategory::BooksPeriodicalsAndNewspapers => "5192",
+            MerchantCategory::BowlingAlleys => "7933",
+            MerchantCategory::BusLines => "4131",
+            MerchantCategory::BusinessSecretarialSchools => "8244",
+            MerchantCategory::BuyingShoppingServices => "7278",
+            MerchantCategory::CableSatelliteAndOtherPayTelevisionAndRadio => "4899",
+            MerchantCategory::CameraAndPhotographicSupplyStores => "5946",
+            MerchantCategory::CandyNutAndConfectioneryStores => "5441",
+            MerchantCategory::CarAndTruckDealersNewUsed => "5511",
+            MerchantCategory::CarAndTruckDealersUsedOnly => "5521",
+            MerchantCategory::CarRentalAgencies => "7512",
+            MerchantCategory::CarWashes => "7542",
+            MerchantCategory::CarpentryServices => "1750",
+            MerchantCategory::CarpetUpholsteryCleaning => "7217",
+            MerchantCategory::Caterers => "5811",
+            MerchantCategory::CharitableAndSocialServiceOrganizationsFundraising => "8398",
+            MerchantCategory::ChemicalsAndAlliedProducts => "5169",
+            MerchantCategory::ChidrensAndInfantsWearStores => "5641",
+            MerchantCategory::ChildCareServices => "8351",
+            MerchantCategory::ChiropodistsPodiatrists => "8049",
+            MerchantCategory::Chiropractors => "8041",
+            MerchantCategory::CigarStoresAndStands => "5993",
+            MerchantCategory::CivicSocialFraternalAssociations => "8641",
+            MerchantCategory::CleaningAndMaintenance => "7349",
+            MerchantCategory::ClothingRental => "7296",
+            MerchantCategory::CollegesUniversities => "8220",
+            MerchantCategory::CommercialEquipment => "5046",
+            MerchantCategory::CommercialFootwear => "5139",
+            MerchantCategory::CommercialPhotographyArtAndGraphics => "7333",
+            MerchantCategory::CommuterTransportAndFerries => "4111",
+            MerchantCategory::ComputerNetworkServices => "4816",
+            MerchantCategory::ComputerProgramming => "7372",
+            MerchantCategory::ComputerRepair => "7379",
+            MerchantCategory::ComputerSoftwareStores => "5734",
+            MerchantCategory::ComputersPeripheralsAndSoftware => "5045",
+            MerchantCategory::ConcreteWorkServices => "1771",
+            MerchantCategory::ConstructionMaterials => "5039",
+            MerchantCategory::ConsultingPublicRelations => "7392",
+            MerchantCategory::CorrespondenceSchools => "8241",
+            MerchantCategory::CosmeticStores => "5977",
+            MerchantCategory::CounselingServices => "7277",
+            MerchantCategory::CountryClubs => "7997",
+            MerchantCategory::CourierServices => "4215",
+            MerchantCategory::CourtCosts => "9211",
+            MerchantCategory::CreditReportingAgencies => "7321",
+            MerchantCategory::CruiseLines => "4411",
+            MerchantCategory::DairyProductsStores => "5451",
+            MerchantCategory::DanceHallStudiosSchools => "7911",
+            MerchantCategory::DatingEscortServices => "7273",
+            MerchantCategory::DentistsOrthodontists => "8021",
+            MerchantCategory::DepartmentStores => "5311",
+            MerchantCategory::DetectiveAgencies => "7393",
+            MerchantCategory::DirectMarketingCatalogMerchant => "5964",
+            MerchantCategory::DirectMarketingCombinationCatalogAndRetailMerchant => "5965",
+            MerchantCategory::DirectMarketingInboundTelemarketing => "5967",
+            MerchantCategory::DirectMarketingInsuranceServices => "5960",
+            MerchantCategory::DirectMarketingOther => "5969",
+            MerchantCategory::DirectMarketingOutboundTelemarketing => "5966",
+            MerchantCategory::DirectMarketingSubscription => "5968",
+            MerchantCategory::DirectMarketingTravel => "5962",
+            MerchantCategory::DiscountStores => "5310",
+            MerchantCategory::Doctors => "8011",
+            MerchantCategory::DoorToDoorSales => "5963",
+            MerchantCategory::DraperyWindowCoveringAndUpholsteryStores => "5714",
+            MerchantCategory::DrinkingPlaces => "5813",
+            MerchantCategory::DrugStoresAndPharmacies => "5912",
+            MerchantCategory::DrugsDrugProprietariesAndDruggistSundries => "5122",
+            MerchantCategory::DryCleaners => "7216",
+            MerchantCategory::DurableGoods => "5099",
+            MerchantCategory::DutyFreeStores => "5309",
+            MerchantCategory::EatingPlacesRestaurants => "5812",
+            MerchantCategory::EducationalServices => "8299",
+            MerchantCategory::ElectricRazorStores => "5997",
+            MerchantCategory::ElectricalPartsAndEquipment => "5065",
+            MerchantCategory::ElectricalServices => "1731",
+            MerchantCategory::ElectronicsRepairShops => "7622",
+            MerchantCategory::ElectronicsStores => "5732",
+            MerchantCategory::ElementarySecondarySchools => "8211",
+            MerchantCategory::EmploymentTempAgencies => "7361",
+            MerchantCategory::EquipmentRental => "7394",
+            MerchantCategory::ExterminatingServices => "7342",
+            MerchantCategory::FamilyClothingStores => "5651",
+            MerchantCategory::FastFoodRestaurants => "5814",
+            MerchantCategory::FinancialInstitutions => "6012",
+            MerchantCategory::FinesGovernmentAdministrativeEntities => "9222",
+            MerchantCategory::FireplaceFireplaceScreensAndAccessoriesStores => "5718",
+            MerchantCategory::FloorCoveringStores => "5713",
+            MerchantCategory::Florists => "5992",
+            MerchantCategory::FloristsSuppliesNurseryStockAndFlowers => "5193",
+            MerchantCategory::FreezerAndLockerMeatProvisioners => "5422",
+            MerchantCategory::FuelDealersNonAutomotive => "5983",
+            MerchantCategory::FuneralServicesCrematories => "7261",
+            MerchantCategory::FurnitureHomeFurnishingsAndEquipmentStoresExceptAppliances => "5712",
+            MerchantCategory::FurnitureRepairRefinishing => "7641",
+            MerchantCategory::FurriersAndFurShops => "5681",
+            MerchantCategory::GeneralServices => "1520",
+            MerchantCategory::GiftCardNoveltyAndSouvenirShops => "5947",
+            MerchantCategory::GlassPaintAndWallpaperStores => "5231",
+            MerchantCategory::GlasswareCrystalStores => "5950",
+            MerchantCategory::GolfCoursesPublic => "7992",
+            MerchantCategory::GovernmentServices => "9399",
+            MerchantCategory::GroceryStoresSupermarkets => "5411",
+            MerchantCategory::HardwareEquipmentAndSupplies => "5072",
+            MerchantCategory::HardwareStores => "5251",
+            MerchantCategory::HealthAndBeautySpas => "7298",
+            MerchantCategory::HearingAidsSalesAndSupplies => "5975",
+            MerchantCategory::HeatingPlumbingAC => "1711",
+            MerchantCategory::HobbyToyAndGameShops => "5945",
+            MerchantCategory::HomeSupplyWarehouseStores => "5200",
+            MerchantCategory::Hospitals => "8062",
+            MerchantCategory::HotelsMotelsAndResorts => "7011",
+            MerchantCategory::HouseholdApplianceStores => "5722",
+            MerchantCategory::IndustrialSupplies => "5085",
+            MerchantCategory::InformationRetrievalServices => "7375",
+            MerchantCategory::InsuranceDefault => "6300",
+            MerchantCategory::InsuranceUnderwritingPremiums => "6300",
+            MerchantCategory::IntraCompanyPurchases => "9950",
+            MerchantCategory::JewelryStoresWatchesClocksAndSilverwareStores => "5944",
+            MerchantCategory::LandscapingServices => "0780",
+            MerchantCategory::Laundries => "7211",
+            MerchantCategory::LaundryCleaningServices => "7210",
+            MerchantCategory::LegalServicesAttorneys => "8111",
+            MerchantCategory::LuggageAndLeatherGoodsStores => "5948",
+            MerchantCategory::LumberBuildingMaterialsStores => "5211",
+            MerchantCategory::ManualCashDisburse => "6010",
+            MerchantCategory::MarinasServiceAndSupplies => "4468",
+            MerchantCategory::MasonryStoneworkAndPlaster => "1740",
+            MerchantCategory::MassageParlors => "7297",
+            MerchantCategory::MedicalAndDentalLabs => "8071",
+            MerchantCategory::MedicalDentalOphthalmicAndHospitalEquipmentAndSupplies => "5047",
+            MerchantCategory::MedicalServices => "8099",
+            MerchantCategory::MembershipOrganizations => "8699",
+            MerchantCategory::MensAndBoysClothingAndAccessoriesStores => "5611",
+            MerchantCategory::MensWomensClothingStores => "5691",
+            MerchantCategory::MetalServiceCenters => "5051",
+            MerchantCategory::Miscellaneous => "7399",
+            MerchantCategory::MiscellaneousApparelAndAccessoryShops => "5699",
+            MerchantCategory::MiscellaneousAutoDealers => "5599",
+            MerchantCategory::MiscellaneousBusinessServices => "7399",
+            MerchantCategory::MiscellaneousFoodStores => "5499",
+            MerchantCategory::MiscellaneousGeneralMerchandise => "5399",
+            MerchantCategory::MiscellaneousGeneralServices => "7299",
+            MerchantCategory::MiscellaneousHomeFurnishingSpecialtyStores => "5719",
+            MerchantCategory::MiscellaneousPublishingAndPrinting => "2741",
+            MerchantCategory::MiscellaneousRecreationServices => "7999",
+            MerchantCategory::MiscellaneousRepairShops => "7699",
+            MerchantCategory::MiscellaneousSpecialtyRetail => "5999",
+            MerchantCategory::MobileHomeDealers => "5271",
+            MerchantCategory::MotionPictureTheaters => "7832",
+            MerchantCategory::MotorFreightCarriersAndTrucking => "4214",
+            MerchantCategory::MotorHomesDealers => "5592",
+            MerchantCategory::MotorVehicleSuppliesAndNewParts => "5013",
+            MerchantCategory::MotorcycleShopsAndDealers => "5571",
+            MerchantCategory::MotorcycleShopsDealers => "5571",
+            MerchantCategory::MusicStoresMusicalInstrumentsPianosAndSheetMusic => "5733",
+            MerchantCategory::NewsDealersAndNewsstands => "5994",
+            MerchantCategory::NonFiMoneyOrders => "6051",
+            MerchantCategory::NonFiStoredValueCardPurchaseLoad => "6540",
+            MerchantCategory::NondurableGoods => "5199",
+            MerchantCategory::NurseriesLawnAndGardenSupplyStores => "5261",
+            MerchantCategory::NursingPersonalCare => "8050",
+            MerchantCategory::OfficeAndCommercialFurniture => "5021",
+            MerchantCategory::OpticiansEyeglasses => "8043",
+            MerchantCategory::OptometristsOphthalmologist => "8042",
+            MerchantCategory::OrthopedicGoodsProstheticDevices => "5976",
+            MerchantCategory::Osteopaths => "8031",
+            MerchantCategory::PackageStoresBeerWineAndLiquor => "5921",
+            MerchantCategory::PaintsVarnishesAndSupplies => "5198",
+            MerchantCategory::ParkingLotsGarages => "7523",
+            MerchantCategory::PassengerRailways => "4112",
+            MerchantCategory::PawnShops => "5933",
+            MerchantCategory::PetShopsPetFoodAndSupplies => "5995",
+            MerchantCategory::PetroleumAndPetroleumProducts => "5172",
+            MerchantCategory::PhotoDeveloping => "7395",
+            MerchantCategory::PhotographicPhotocopyMicrofilmEquipmentAndSupplies => "5044",
+            MerchantCategory::PhotographicStudios => "7221",
+            MerchantCategory::PictureVideoProduction => "7829",
+            MerchantCategory::PieceGoodsNotionsAndOtherDryGoods => "5131",
+            MerchantCategory::PlumbingHeatingEquipmentAndSupplies => "5074",
+            MerchantCategory::PoliticalOrganizations => "8651",
+            MerchantCategory::PostalServicesGovernmentOnly => "9402",
+            MerchantCategory::PreciousStonesAndMetalsWatchesAndJewelry => "5094",
+            MerchantCategory::ProfessionalServices => "8999",
+            MerchantCategory::PublicWarehousingAndStorage => "4225",
+            MerchantCategory::QuickCopyReproAndBlueprint => "7338",
+            MerchantCategory::Railroads => "4011",
+            MerchantCategory::RealEstateAgentsAndManagersRentals => "6513",
+            MerchantCategory::RecordStores => "5735",
+            MerchantCategory::RecreationalVehicleRentals => "7519",
+            MerchantCategory::ReligiousGoodsStores => "5973",
+            MerchantCategory::ReligiousOrganizations => "8661",
+            MerchantCategory::RoofingSidingSheetMetal => "1761",
+            MerchantCategory::SecretarialSupportServices => "7339",
+            MerchantCategory::SecurityBrokersDealers => "6211",
+            MerchantCategory::ServiceStations => "5541",
+            MerchantCategory::SewingNeedleworkFabricAndPieceGoodsStores => "5949",
+            MerchantCategory::ShoeRepairHatCleaning => "7251",
+            MerchantCategory::ShoeStores => "5661",
+            MerchantCategory::SmallApplianceRepair => "7629",
+            MerchantCategory::SnowmobileDealers => "5598",
+            MerchantCategory::SpecialTradeServices => "1799",
+            MerchantCategory::SpecialtyCleaning => "2842",
+            MerchantCategory::SportingGoodsStores => "5941",
+            MerchantCategory::SportingRecreationCamps => "7032",
+            MerchantCategory::SportsAndRidingApparelStores => "5655",
+            MerchantCategory::SportsClubsFields => "7941",
+            MerchantCategory::StampAndCoinStores => "5972",
+            MerchantCategory::StationaryOfficeSuppliesPrintingAndWritingPaper => "5111",
+            MerchantCategory::StationeryStoresOfficeAndSchoolSupplyStores => "5943",
+            MerchantCategory::SwimmingPoolsSales => "5996",
+            MerchantCategory::TUiTravelGermany => "4723",
+            MerchantCategory::TailorsAlterations => "5697",
+            MerchantCategory::TaxPaymentsGovernmentAgencies => "9311",
+            MerchantCategory::TaxPreparationServices => "7276",
+            MerchantCategory::TaxicabsLimousines => "4121",
+            MerchantCategory::TelecommunicationEquipmentAndTelephoneSales => "4812",
+            MerchantCategory::TelecommunicationServices => "4814",
+            MerchantCategory::TelegraphServices => "4821",
+            MerchantCategory::TentAndAwningShops => "5998",
+            MerchantCategory::TestingLaboratories => "8734",
+            MerchantCategory::TheatricalTicketAgencies => "7922",
+            MerchantCategory::Timeshares => "7012",
+            MerchantCategory::TireRetreadingAndRepair => "7534",
+            MerchantCategory::TollsBridgeFees => "4784",
+            MerchantCategory::TouristAttractionsAndExhibits => "7991",
+            MerchantCategory::TowingServices => "7549",
+            MerchantCategory::TrailerParksCampgrounds => "7033",
+            MerchantCategory::TransportationServices => "4789",
+            MerchantCategory::TravelAgenciesTourOperators => "4722",
+            MerchantCategory::TruckStopIteration => "7511",
+            MerchantCategory::TruckUtilityTrailerRentals => "7513",
+            MerchantCategory::TypesettingPlateMakingAndRelatedServices => "2791",
+            MerchantCategory::TypewriterStores => "5978",
+            MerchantCategory::USFederalGovernmentAgenciesOrDepartments => "9405",
+            MerchantCategory::UniformsCommercialClothing => "5137",
+            MerchantCategory::UsedMerchandiseAndSecondhandStores => "5931",
+            MerchantCategory::Utilities => "4900",
+            MerchantCategory::VarietyStores => "5331",
+            MerchantCategory::VeterinaryServices => "0742",
+            MerchantCategory::VideoAmusementGameSupplies => "7993",
+            MerchantCategory::VideoGameArcades => "7994",
+            MerchantCategory::VideoTapeRentalStores => "7841",
+            MerchantCategory::VocationalTradeSchools => "8249",
+            MerchantCategory::WatchJewelryRepair => "7631",
+            MerchantCategory::WeldingRepair => "7692",
+            MerchantCategory::WholesaleClubs => "5300",
+            MerchantCategory::WigAndToupeeStores => "5698",
+            MerchantCategory::WiresMoneyOrders => "4829",
+            MerchantCategory::WomensAccessoryAndSpecialtyShops => "5631",
+            MerchantCategory::WomensReadyToWearStores => "5621",
+            MerchantCategory::WreckingAndSalvageYards => "5935",
+        }
+    }
+
+    /// Looks up a `MerchantCategory` from its four-digit [merchant category code](https://stripe.com/docs/issuing/merchant-categories) (MCC).
+    ///
+    /// Returns `None` if `code` does not correspond to a known category.
+    pub fn from_mcc(code: &str) -> Option<MerchantCategory> {
+        match code {
+            "0742" => Some(MerchantCategory::VeterinaryServices),
+            "0763" => Some(MerchantCategory::AgriculturalCooperative),
+            "0780" => Some(MerchantCategory::LandscapingServices),
+            "1520" => Some(MerchantCategory::GeneralServices),
+            "1711" => Some(MerchantCategory::HeatingPlumbingAC),
+            "1731" => Some(MerchantCategory::ElectricalServices),
+            "1740" => Some(MerchantCategory::MasonryStoneworkAndPlaster),
+            "1750" => Some(MerchantCategory::CarpentryServices),
+            "1761" => Some(MerchantCategory::RoofingSidingSheetMetal),
+            "1771" => Some(MerchantCategory::ConcreteWorkServices),
+            "1799" => Some(MerchantCategory::SpecialTradeServices),
+            "2741" => Some(MerchantCategory::MiscellaneousPublishingAndPrinting),
+            "2791" => Some(MerchantCategory::TypesettingPlateMakingAndRelatedServices),
+            "2842" => Some(MerchantCategory::SpecialtyCleaning),
+            "4011" => Some(MerchantCategory::Railroads),
+            "4111" => Some(MerchantCategory::CommuterTransportAndFerries),
+            "4112" => Some(MerchantCategory::PassengerRailways),
+            "4119" => Some(MerchantCategory::AmbulanceServices),
+            "4121" => Some(MerchantCategory::TaxicabsLimousines),
+            "4131" => Some(MerchantCategory::BusLines),
+            "4214" => Some(MerchantCategory::MotorFreightCarriersAndTrucking),
+            "4215" => Some(MerchantCategory::CourierServices),
+            "4225" => Some(MerchantCategory::PublicWarehousingAndStorage),
+            "4411" => Some(MerchantCategory::CruiseLines),
+            "4457" => Some(MerchantCategory::BoatRentalsAndLeases),
+            "4468" => Some(MerchantCategory::MarinasServiceAndSupplies),
+            "4511" => Some(MerchantCategory::AirlinesAirCarriers),
+            "4582" => Some(MerchantCategory::AirportsFlyingFields),
+            "4722" => Some(MerchantCategory::TravelAgenciesTourOperators),
+            "4723" => Some(MerchantCategory::TUiTravelGermany),
+            "4784" => Some(MerchantCategory::TollsBridgeFees),
+            "4789" => Some(MerchantCategory::TransportationServices),
+            "4812" => Some(MerchantCategory::TelecommunicationEquipmentAndTelephoneSales),
+            "4814" => Some(MerchantCategory::TelecommunicationServices),
+            "4816" => Some(MerchantCategory::ComputerNetworkServices),
+            "4821" => Some(MerchantCategory::TelegraphServices),
+            "4829" => Some(MerchantCategory::WiresMoneyOrders),
+            "4899" => Some(MerchantCategory::CableSatelliteAndOtherPayTelevisionAndRadio),
+            "4900" => Some(MerchantCategory::Utilities),
+            "5013" => Some(MerchantCategory::MotorVehicleSuppliesAndNewParts),
+            "5021" => Some(MerchantCategory::OfficeAndCommercialFurniture),
+            "5039" => Some(MerchantCategory::ConstructionMaterials),
+            "5044" => Some(MerchantCategory::PhotographicPhotocopyMicrofilmEquipmentAndSupplies),
+            "5045" => Some(MerchantCategory::ComputersPeripheralsAndSoftware),
+            "5046" => Some(MerchantCategory::CommercialEquipment),
+            "5047" => Some(MerchantCategory::MedicalDentalOphthalmicAndHospitalEquipmentAndSupplies),
+            "5051" => Some(MerchantCategory::MetalServiceCenters),
+            "5065" => Some(MerchantCategory::ElectricalPartsAndEquipment),
+            "5072" => Some(MerchantCategory::HardwareEquipmentAndSupplies),
+            "5074" => Some(MerchantCategory::PlumbingHeatingEquipmentAndSupplies),
+            "5085" => Some(MerchantCategory::IndustrialSupplies),
+            "5094" => Some(MerchantCategory::PreciousStonesAndMetalsWatchesAndJewelry),
+            "5099" => Some(MerchantCategory::DurableGoods),
+            "5111" => Some(MerchantCategory::StationaryOfficeSuppliesPrintingAndWritingPaper),
+            "5122" => Some(MerchantCategory::DrugsDrugProprietariesAndDruggistSundries),
+            "5131" => Some(MerchantCategory::PieceGoodsNotionsAndOtherDryGoods),
+            "5137" => Some(MerchantCategory::UniformsCommercialClothing),
+            "5139" => Some(MerchantCategory::CommercialFootwear),
+            "5169" => Some(MerchantCategory::ChemicalsAndAlliedProducts),
+            "5172" => Some(MerchantCategory::PetroleumAndPetroleumProducts),
+            "5192" => Some(MerchantCategory::BooksPeriodicalsAndNewspapers),
+            "5193" => Some(MerchantCategory::FloristsSuppliesNurseryStockAndFlowers),
+            "5198" => Some(MerchantCategory::PaintsVarnishesAndSupplies),
+            "5199" => Some(MerchantCategory::NondurableGoods),
+            "5200" => Some(MerchantCategory::HomeSupplyWarehouseStores),
+            "5211" => Some(MerchantCategory::LumberBuildingMaterialsStores),
+            "5231" => Some(MerchantCategory::GlassPaintAndWallpaperStores),
+            "5251" => Some(MerchantCategory::HardwareStores),
+            "5261" => Some(MerchantCategory::NurseriesLawnAndGardenSupplyStores),
+            "5271" => Some(MerchantCategory::MobileHomeDealers),
+            "5300" => Some(MerchantCategory::WholesaleClubs),
+            "5309" => Some(MerchantCategory::DutyFreeStores),
+            "5310" => Some(MerchantCategory::DiscountStores),
+            "5311" => Some(MerchantCategory::DepartmentStores),
+            "5331" => Some(MerchantCategory::VarietyStores),
+            "5399" => Some(MerchantCategory::MiscellaneousGeneralMerchandise),
+            "5411" => Some(MerchantCategory::GroceryStoresSupermarkets),
+            "5422" => Some(MerchantCategory::FreezerAndLockerMeatProvisioners),
+            "5441" => Some(MerchantCategory::CandyNutAndConfectioneryStores),
+            "5451" => Some(MerchantCategory::DairyProductsStores),
+            "5462" => Some(MerchantCategory::Bakeries),
+            "5499" => Some(MerchantCategory::MiscellaneousFoodStores),
+            "5511" => Some(MerchantCategory::CarAndTruckDealersNewUsed),
+            "5521" => Some(MerchantCategory::CarAndTruckDealersUsedOnly),
+            "5531" => Some(MerchantCategory::AutoAndHomeSupplyStores),
+            "5532" => Some(MerchantCategory::AutomotiveTireStores),
+            "5533" => Some(MerchantCategory::AutomotivePartsAndAccessoriesStores),
+            "5541" => Some(MerchantCategory::ServiceStations),
+            "5542" => Some(MerchantCategory::AutomatedFuelDispensers),
+            "5551" => Some(MerchantCategory::BoatDealers),
+            "5571" => Some(MerchantCategory::MotorcycleShopsAndDealers),
+            "5592" => Some(MerchantCategory::MotorHomesDealers),
+            "5598" => Some(MerchantCategory::SnowmobileDealers),
+            "5599" => Some(MerchantCategory::MiscellaneousAutoDealers),
+            "5611" => Some(MerchantCategory::MensAndBoysClothingAndAccessoriesStores),
+            "5621" => Some(MerchantCategory::WomensReadyToWearStores),
+            "5631" => Some(MerchantCategory::WomensAccessoryAndSpecialtyShops),
+            "5641" => Some(MerchantCategory::ChidrensAndInfantsWearStores),
+            "5651" => Some(MerchantCategory::FamilyClothingStores),
+            "5655" => Some(MerchantCategory::SportsAndRidingApparelStores),
+            "5661" => Some(MerchantCategory::ShoeStores),
+            "5681" => Some(MerchantCategory::FurriersAndFurShops),
+            "5691" => Some(MerchantCategory::MensWomensClothingStores),
+            "5697" => Some(MerchantCategory::TailorsAlterations),
+            "5698" => Some(MerchantCategory::WigAndToupeeStores),
+            "5699" => Some(MerchantCategory::MiscellaneousApparelAndAccessoryShops),
+            "5712" => Some(MerchantCategory::FurnitureHomeFurnishingsAndEquipmentStoresExceptAppliances),
+            "5713" => Some(MerchantCategory::FloorCoveringStores),
+            "5714" => Some(MerchantCategory::DraperyWindowCoveringAndUpholsteryStores),
+            "5718" => Some(MerchantCategory::FireplaceFireplaceScreensAndAccessoriesStores),
+            "5719" => Some(MerchantCategory::MiscellaneousHomeFurnishingSpecialtyStores),
+            "5722" => Some(MerchantCategory::HouseholdApplianceStores),
+            "5732" => Some(MerchantCategory::ElectronicsStores),
+            "5733" => Some(MerchantCategory::MusicStoresMusicalInstrumentsPianosAndSheetMusic),
+            "5734" => Some(MerchantCategory::ComputerSoftwareStores),
+            "5735" => Some(MerchantCategory::RecordStores),
+            "5811" => Some(MerchantCategory::Caterers),
+            "5812" => Some(MerchantCategory::EatingPlacesRestaurants),
+            "5813" => Some(MerchantCategory::DrinkingPlaces),
+            "5814" => Some(MerchantCategory::FastFoodRestaurants),
+            "5912" => Some(MerchantCategory::DrugStoresAndPharmacies),
+            "5921" => Some(MerchantCategory::PackageStoresBeerWineAndLiquor),
+            "5931" => Some(MerchantCategory::UsedMerchandiseAndSecondhandStores),
+            "5932" => Some(MerchantCategory::AntiqueShops),
+            "5933" => Some(MerchantCategory::PawnShops),
+            "5935" => Some(MerchantCategory::WreckingAndSalvageYards),
+            "5937" => Some(MerchantCategory::AntiqueReproductions),
+            "5940" => Some(MerchantCategory::BicycleShops),
+            "5941" => Some(MerchantCategory::SportingGoodsStores),
+            "5942" => Some(MerchantCategory::BookStores),
+            "5943" => Some(MerchantCategory::StationeryStoresOfficeAndSchoolSupplyStores),
+            "5944" => Some(MerchantCategory::JewelryStoresWatchesClocksAndSilverwareStores),
+            "5945" => Some(MerchantCategory::HobbyToyAndGameShops),
+            "5946" => Some(MerchantCategory::CameraAndPhotographicSupplyStores),
+            "5947" => Some(MerchantCategory::GiftCardNoveltyAndSouvenirShops),
+            "5948" => Some(MerchantCategory::LuggageAndLeatherGoodsStores),
+            "5949" => Some(MerchantCategory::SewingNeedleworkFabricAndPieceGoodsStores),
+            "5950" => Some(MerchantCategory::GlasswareCrystalStores),
+            "5960" => Some(MerchantCategory::DirectMarketingInsuranceServices),
+            "5962" => Some(MerchantCategory::DirectMarketingTravel),
+            "5963" => Some(MerchantCategory::DoorToDoorSales),
+            "5964" => Some(MerchantCategory::DirectMarketingCatalogMerchant),
+            "5965" => Some(MerchantCategory::DirectMarketingCombinationCatalogAndRetailMerchant),
+            "5966" => Some(MerchantCategory::DirectMarketingOutboundTelemarketing),
+            "5967" => Some(MerchantCategory::DirectMarketingInboundTelemarketing),
+            "5968" => Some(MerchantCategory::DirectMarketingSubscription),
+            "5969" => Some(MerchantCategory::DirectMarketingOther),
+            "5970" => Some(MerchantCategory::ArtistsSupplyAndCraftShops),
+            "5971" => Some(MerchantCategory::ArtDealersAndGalleries),
+            "5972" => Some(MerchantCategory::StampAndCoinStores),
+            "5973" => Some(MerchantCategory::ReligiousGoodsStores),
+            "5975" => Some(MerchantCategory::HearingAidsSalesAndSupplies),
+            "5976" => Some(MerchantCategory::OrthopedicGoodsProstheticDevices),
+            "5977" => Some(MerchantCategory::CosmeticStores),
+            "5978" => Some(MerchantCategory::TypewriterStores),
+            "5983" => Some(MerchantCategory::FuelDealersNonAutomotive),
+            "5992" => Some(MerchantCategory::Florists),
+            "5993" => Some(MerchantCategory::CigarStoresAndStands),
+            "5994" => Some(MerchantCategory::NewsDealersAndNewsstands),
+            "5995" => Some(MerchantCategory::PetShopsPetFoodAndSupplies),
+            "5996" => Some(MerchantCategory::SwimmingPoolsSales),
+            "5997" => Some(MerchantCategory::ElectricRazorStores),
+            "5998" => Some(MerchantCategory::TentAndAwningShops),
+            "5999" => Some(MerchantCategory::MiscellaneousSpecialtyRetail),
+            "6010" => Some(MerchantCategory::ManualCashDisburse),
+            "6011" => Some(MerchantCategory::AutomatedCashDisburse),
+            "6012" => Some(MerchantCategory::FinancialInstitutions),
+            "6051" => Some(MerchantCategory::NonFiMoneyOrders),
+            "6211" => Some(MerchantCategory::SecurityBrokersDealers),
+            "6300" => Some(MerchantCategory::InsuranceDefault),
+            "6513" => Some(MerchantCategory::RealEstateAgentsAndManagersRentals),
+            "6540" => Some(MerchantCategory::NonFiStoredValueCardPurchaseLoad),
+            "7011" => Some(MerchantCategory::HotelsMotelsAndResorts),
+            "7012" => Some(MerchantCategory::Timeshares),
+            "7032" => Some(MerchantCategory::SportingRecreationCamps),
+            "7033" => Some(MerchantCategory::TrailerParksCampgrounds),
+            "7210" => Some(MerchantCategory::LaundryCleaningServices),
+            "7211" => Some(MerchantCategory::Laundries),
+            "7216" => Some(MerchantCategory::DryCleaners),
+            "7217" => Some(MerchantCategory::CarpetUpholsteryCleaning),
+            "7221" => Some(MerchantCategory::PhotographicStudios),
+            "7230" => Some(MerchantCategory::BarberAndBeautyShops),
+            "7251" => Some(MerchantCategory::ShoeRepairHatCleaning),
+            "7261" => Some(MerchantCategory::FuneralServicesCrematories),
+            "7273" => Some(MerchantCategory::DatingEscortServices),
+            "7276" => Some(MerchantCategory::TaxPreparationServices),
+            "7277" => Some(MerchantCategory::CounselingServices),
+            "7278" => Some(MerchantCategory::BuyingShoppingServices),
+            "7296" => Some(MerchantCategory::ClothingRental),
+            "7297" => Some(MerchantCategory::MassageParlors),
+            "7298" => Some(MerchantCategory::HealthAndBeautySpas),
+            "7299" => Some(MerchantCategory::MiscellaneousGeneralServices),
+            "7311" => Some(MerchantCategory::AdvertisingServices),
+            "7321" => Some(MerchantCategory::CreditReportingAgencies),
+            "7333" => Some(MerchantCategory::CommercialPhotographyArtAndGraphics),
+            "7338" => Some(MerchantCategory::QuickCopyReproAndBlueprint),
+            "7339" => Some(MerchantCategory::SecretarialSupportServices),
+            "7342" => Some(MerchantCategory::ExterminatingServices),
+            "7349" => Some(MerchantCategory::CleaningAndMaintenance),
+            "7361" => Some(MerchantCategory::EmploymentTempAgencies),
+            "7372" => Some(MerchantCategory::ComputerProgramming),
+            "7375" => Some(MerchantCategory::InformationRetrievalServices),
+            "7379" => Some(MerchantCategory::ComputerRepair),
+            "7392" => Some(MerchantCategory::ConsultingPublicRelations),
+            "7393" => Some(MerchantCategory::DetectiveAgencies),
+            "7394" => Some(MerchantCategory::EquipmentRental),
+            "7395" => Some(MerchantCategory::PhotoDeveloping),
+            "7399" => Some(MerchantCategory::Miscellaneous),
+            "7511" => Some(MerchantCategory::TruckStopIteration),
+            "7512" => Some(MerchantCategory::CarRentalAgencies),
+            "7513" => Some(MerchantCategory::TruckUtilityTrailerRentals),
+            "7519" => Some(MerchantCategory::RecreationalVehicleRentals),
+            "7523" => Some(MerchantCategory::ParkingLotsGarages),
+            "7531" => Some(MerchantCategory::AutoBodyRepairShops),
+            "7534" => Some(MerchantCategory::TireRetreadingAndRepair),
+            "7535" => Some(MerchantCategory::AutoPaintShops),
+            "7538" => Some(MerchantCategory::AutoServiceShops),
+            "7542" => Some(MerchantCategory::CarWashes),
+            "7549" => Some(MerchantCategory::TowingServices),
+            "7622" => Some(MerchantCategory::ElectronicsRepairShops),
+            "7623" => Some(MerchantCategory::AcRefrigerationRepair),
+            "7629" => Some(MerchantCategory::SmallApplianceRepair),
+            "7631" => Some(MerchantCategory::WatchJewelryRepair),
+            "7641" => Some(MerchantCategory::FurnitureRepairRefinishing),
+            "7692" => Some(MerchantCategory::WeldingRepair),
+            "7699" => Some(MerchantCategory::MiscellaneousRepairShops),
+            "7829" => Some(MerchantCategory::PictureVideoProduction),
+            "7832" => Some(MerchantCategory::MotionPictureTheaters),
+            "7841" => Some(MerchantCategory::VideoTapeRentalStores),
+            "7911" => Some(MerchantCategory::DanceHallStudiosSchools),
+            "7922" => Some(MerchantCategory::TheatricalTicketAgencies),
+            "7929" => Some(MerchantCategory::BandsOrchestras),
+            "7932" => Some(MerchantCategory::BilliardPoolEstablishments),
+            "7933" => Some(MerchantCategory::BowlingAlleys),
+            "7941" => Some(MerchantCategory::SportsClubsFields),
+            "7991" => Some(MerchantCategory::TouristAttractionsAndExhibits),
+            "7992" => Some(MerchantCategory::GolfCoursesPublic),
+            "7993" => Some(MerchantCategory::VideoAmusementGameSupplies),
+            "7994" => Some(MerchantCategory::VideoGameArcades),
+            "7995" => Some(MerchantCategory::BettingCasinoGambling),
+            "7996" => Some(MerchantCategory::AmusementParksCarnivals),
+            "7997" => Some(MerchantCategory::CountryClubs),
+            "7998" => Some(MerchantCategory::Aquariums),
+            "7999" => Some(MerchantCategory::MiscellaneousRecreationServices),
+            "8011" => Some(MerchantCategory::Doctors),
+            "8021" => Some(MerchantCategory::DentistsOrthodontists),
+            "8031" => Some(MerchantCategory::Osteopaths),
+            "8041" => Some(MerchantCategory::Chiropractors),
+            "8042" => Some(MerchantCategory::OptometristsOphthalmologist),
+            "8043" => Some(MerchantCategory::OpticiansEyeglasses),
+            "8049" => Some(MerchantCategory::ChiropodistsPodiatrists),
+            "8050" => Some(MerchantCategory::NursingPersonalCare),
+            "8062" => Some(MerchantCategory::Hospitals),
+            "8071" => Some(MerchantCategory::MedicalAndDentalLabs),
+            "8099" => Some(MerchantCategory::MedicalServices),
+            "8111" => Some(MerchantCategory::LegalServicesAttorneys),
+            "8211" => Some(MerchantCategory::ElementarySecondarySchools),
+            "8220" => Some(MerchantCategory::CollegesUniversities),
+            "8241" => Some(MerchantCategory::CorrespondenceSchools),
+            "8244" => Some(MerchantCategory::BusinessSecretarialSchools),
+            "8249" => Some(MerchantCategory::VocationalTradeSchools),
+            "8299" => Some(MerchantCategory::EducationalServices),
+            "8351" => Some(MerchantCategory::ChildCareServices),
+            "8398" => Some(MerchantCategory::CharitableAndSocialServiceOrganizationsFundraising),
+            "8641" => Some(MerchantCategory::CivicSocialFraternalAssociations),
+            "8651" => Some(MerchantCategory::PoliticalOrganizations),
+            "8661" => Some(MerchantCategory::ReligiousOrganizations),
+            "8675" => Some(MerchantCategory::AutomobileAssociations),
+            "8699" => Some(MerchantCategory::MembershipOrganizations),
+            "8734" => Some(MerchantCategory::TestingLaboratories),
+            "8911" => Some(MerchantCategory::ArchitecturalSurveyingServices),
+            "8931" => Some(MerchantCategory::AccountingBookkeepingServices),
+            "8999" => Some(MerchantCategory::ProfessionalServices),
+            "9211" => Some(MerchantCategory::CourtCosts),
+            "9222" => Some(MerchantCategory::FinesGovernmentAdministrativeEntities),
+            "9223" => Some(MerchantCategory::BailAndBondPayments),
+            "9311" => Some(MerchantCategory::TaxPaymentsGovernmentAgencies),
+            "9399" => Some(MerchantCategory::GovernmentServices),
+            "9402" => Some(MerchantCategory::PostalServicesGovernmentOnly),
+            "9405" => Some(MerchantCategory::USFederalGovernmentAgenciesOrDepartments),
+            "9950" => Some(MerchantCategory::IntraCompanyPurchases),
+            _ => None,
+        }
+    }
+
+    /// A human-readable label for this category, suitable for display in a UI.
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            MerchantCategory::AcRefrigerationRepair => "AC Refrigeration Repair",
+            MerchantCategory::AccountingBookkeepingServices => "Accounting Bookkeeping Services",
+            MerchantCategory::AdvertisingServices => "Advertising Services",
+            MerchantCategory::AgriculturalCooperative => "Agricultural Cooperative",
+            MerchantCategory::AirlinesAirCarriers => "Airlines Air Carriers",
+            MerchantCategory::AirportsFlyingFields => "Airports Flying Fields",
+            MerchantCategory::AmbulanceServices => "Ambulance Services",
+            MerchantCategory::AmusementParksCarnivals => "Amusement Parks Carnivals",
+            MerchantCategory::AntiqueReproductions => "Antique Reproductions",
+            MerchantCategory::AntiqueShops => "Antique Shops",
+            MerchantCategory::Aquariums => "Aquariums",
+            MerchantCategory::ArchitecturalSurveyingServices => "Architectural Surveying Services",
+            MerchantCategory::ArtDealersAndGalleries => "Art Dealers And Galleries",
+            MerchantCategory::ArtistsSupplyAndCraftShops => "Artists Supply And Craft Shops",
+            MerchantCategory::AutoAndHomeSupplyStores => "Auto And Home Supply Stores",
+            MerchantCategory::AutoBodyRepairShops => "Auto Body Repair Shops",
+            MerchantCategory::AutoPaintShops => "Auto Paint Shops",
+            MerchantCategory::AutoServiceShops => "Auto Service Shops",
+            MerchantCategory::AutomatedCashDisburse => "Automated Cash Disburse",
+            MerchantCategory::AutomatedFuelDispensers => "Automated Fuel Dispensers",
+            MerchantCategory::AutomobileAssociations => "Automobile Associations",
+            MerchantCategory::AutomotivePartsAndAccessoriesStores => "Automotive Parts And Accessories Stores",
+            MerchantCategory::AutomotiveTireStores => "Automotive Tire Stores",
+            MerchantCategory::BailAndBondPayments => "Bail And Bond Payments",
+            MerchantCategory::Bakeries => "Bakeries",
+            MerchantCategory::BandsOrchestras => "Bands Orchestras",
+            MerchantCategory::BarberAndBeautyShops => "Barber And Beauty Shops",
+            MerchantCategory::BettingCasinoGambling => "Betting Casino Gambling",
+            MerchantCategory::BicycleShops => "Bicycle Shops",
+            MerchantCategory::BilliardPoolEstablishments => "Billiard Pool Establishments",
+            MerchantCategory::BoatDealers => "Boat Dealers",
+            MerchantCategory::BoatRentalsAndLeases => "Boat Rentals And Leases",
+            MerchantCategory::BookStores => "Book Stores",
+            MerchantCategory::BooksPeriodicalsAndNewspapers => "Books Periodicals And Newspapers",
+            MerchantCategory::BowlingAlleys => "Bowling Alleys",
+            MerchantCategory::BusLines => "Bus Lines",
+            MerchantCategory::BusinessSecretarialSchools => "Business Secretarial Schools",
+            MerchantCategory::BuyingShoppingServices => "Buying Shopping Services",
+            MerchantCategory::CableSatelliteAndOtherPayTelevisionAndRadio => "Cable Satellite And Other Pay Television And Radio",
+            MerchantCategory::CameraAndPhotographicSupplyStores => "Camera And Photographic Supply Stores",
+            MerchantCategory::CandyNutAndConfectioneryStores => "Candy Nut And Confectionery Stores",
+            MerchantCategory::CarAndTruckDealersNewUsed => "Car And Truck Dealers New Used",
+            MerchantCategory::CarAndTruckDealersUsedOnly => "Car And Truck Dealers Used Only",
+            MerchantCategory::CarRentalAgencies => "Car Rental Agencies",
+            MerchantCategory::CarWashes => "Car Washes",
+            MerchantCategory::CarpentryServices => "Carpentry Services",
+            MerchantCategory::CarpetUpholsteryCleaning => "Carpet Upholstery Cleaning",
+            MerchantCategory::Caterers => "Caterers",
+            MerchantCategory::CharitableAndSocialServiceOrganizationsFundraising => "Charitable And Social Service Organizations Fundraising",
+            MerchantCategory::ChemicalsAndAlliedProducts => "Chemicals And Allied Products",
+            MerchantCategory::ChidrensAndInfantsWearStores => "Chidrens And Infants Wear Stores",
+            MerchantCategory::ChildCareServices => "Child Care Services",
+            MerchantCategory::ChiropodistsPodiatrists => "Chiropodists Podiatrists",
+            MerchantCategory::Chiropractors => "Chiropractors",
+            MerchantCategory::CigarStoresAndStands => "Cigar Stores And Stands",
+            MerchantCategory::CivicSocialFraternalAssociations => "Civic Social Fraternal Associations",
+            MerchantCategory::CleaningAndMaintenance => "Cleaning And Maintenance",
+            MerchantCategory::ClothingRental => "Clothing Rental",
+            MerchantCategory::CollegesUniversities => "Colleges Universities",
+            MerchantCategory::CommercialEquipment => "Commercial Equipment",
+            MerchantCategory::CommercialFootwear => "Commercial Footwear",
+            MerchantCategory::CommercialPhotographyArtAndGraphics => "Commercial Photography Art And Graphics",
+            MerchantCategory::CommuterTransportAndFerries => "Commuter Transport And Ferries",
+            MerchantCategory::ComputerNetworkServices => "Computer Network Services",
+            MerchantCategory::ComputerProgramming => "Computer Programming",
+            MerchantCategory::ComputerRepair => "Computer Repair",
+            MerchantCategory::ComputerSoftwareStores => "Computer Software Stores",
+            MerchantCategory::ComputersPeripheralsAndSoftware => "Computers Peripherals And Software",
+            MerchantCategory::ConcreteWorkServices => "Concrete Work Services",
+            MerchantCategory::ConstructionMaterials => "Construction Materials",
+            MerchantCategory::ConsultingPublicRelations => "Consulting Public Relations",
+            MerchantCategory::CorrespondenceSchools => "Correspondence Schools",
+            MerchantCategory::CosmeticStores => "Cosmetic Stores",
+            MerchantCategory::CounselingServices => "Counseling Services",
+            MerchantCategory::CountryClubs => "Country Clubs",
+            MerchantCategory::CourierServices => "Courier Services",
+            MerchantCategory::CourtCosts => "Court Costs",
+            MerchantCategory::CreditReportingAgencies => "Credit Reporting Agencies",
+            MerchantCategory::CruiseLines => "Cruise Lines",
+            MerchantCategory::DairyProductsStores => "Dairy Products Stores",
+            MerchantCategory::DanceHallStudiosSchools => "Dance Hall Studios Schools",
+            MerchantCategory::DatingEscortServices => "Dating Escort Services",
+            MerchantCategory::DentistsOrthodontists => "Dentists Orthodontists",
+            MerchantCategory::DepartmentStores => "Department Stores",
+            MerchantCategory::DetectiveAgencies => "Detective Agencies",
+            MerchantCategory::DirectMarketingCatalogMerchant => "Direct Marketing Catalog Merchant",
+            MerchantCategory::DirectMarketingCombinationCatalogAndRetailMerchant => "Direct Marketing Combination Catalog And Retail Merchant",
+            MerchantCategory::DirectMarketingInboundTelemarketing => "Direct Marketing Inbound Telemarketing",
+            MerchantCategory::DirectMarketingInsuranceServices => "Direct Marketing Insurance Services",
+            MerchantCategory::DirectMarketingOther => "Direct Marketing Other",
+            MerchantCategory::DirectMarketingOutboundTelemarketing => "Direct Marketing Outbound Telemarketing",
+            MerchantCategory::DirectMarketingSubscription => "Direct Marketing Subscription",
+            MerchantCategory::DirectMarketingTravel => "Direct Marketing Travel",
+            MerchantCategory::DiscountStores => "Discount Stores",
+            MerchantCategory::Doctors => "Doctors",
+            MerchantCategory::DoorToDoorSales => "Door To Door Sales",
+            MerchantCategory::DraperyWindowCoveringAndUpholsteryStores => "Drapery Window Covering And Upholstery Stores",
+            MerchantCategory::DrinkingPlaces => "Drinking Places",
+            MerchantCategory::DrugStoresAndPharmacies => "Drug Stores And Pharmacies",
+            MerchantCategory::DrugsDrugProprietariesAndDruggistSundries => "Drugs Drug Proprietaries And Druggist Sundries",
+            MerchantCategory::DryCleaners => "Dry Cleaners",
+            MerchantCategory::DurableGoods => "Durable Goods",
+            MerchantCategory::DutyFreeStores => "Duty Free Stores",
+            MerchantCategory::EatingPlacesRestaurants => "Eating Places Restaurants",
+            MerchantCategory::EducationalServices => "Educational Services",
+            MerchantCategory::ElectricRazorStores => "Electric Razor Stores",
+            MerchantCategory::ElectricalPartsAndEquipment => "Electrical Parts And Equipment",
+            MerchantCategory::ElectricalServices => "Electrical Services",
+            MerchantCategory::ElectronicsRepairShops => "Electronics Repair Shops",
+            MerchantCategory::ElectronicsStores => "Electronics Stores",
+            MerchantCategory::ElementarySecondarySchools => "Elementary Secondary Schools",
+            MerchantCategory::EmploymentTempAgencies => "Employment Temp Agencies",
+            MerchantCategory::EquipmentRental => "Equipment Rental",
+            MerchantCategory::ExterminatingServices => "Exterminating Services",
+            MerchantCategory::FamilyClothingStores => "Family Clothing Stores",
+            MerchantCategory::FastFoodRestaurants => "Fast Food Restaurants",
+            MerchantCategory::FinancialInstitutions => "Financial Institutions",
+            MerchantCategory::FinesGovernmentAdministrativeEntities => "Fines Government Administrative Entities",
+            MerchantCategory::FireplaceFireplaceScreensAndAccessoriesStores => "Fireplace Fireplace Screens And Accessories Stores",
+            MerchantCategory::FloorCoveringStores => "Floor Covering Stores",
+            MerchantCategory::Florists => "Florists",
+            MerchantCategory::FloristsSuppliesNurseryStockAndFlowers => "Florists Supplies Nursery Stock And Flowers",
+            MerchantCategory::FreezerAndLockerMeatProvisioners => "Freezer And Locker Meat Provisioners",
+            MerchantCategory::FuelDealersNonAutomotive => "Fuel Dealers Non Automotive",
+            MerchantCategory::FuneralServicesCrematories => "Funeral Services Crematories",
+            MerchantCategory::FurnitureHomeFurnishingsAndEquipmentStoresExceptAppliances => "Furniture Home Furnishings And Equipment Stores Except Appliances",
+            MerchantCategory::FurnitureRepairRefinishing => "Furniture Repair Refinishing",
+            MerchantCategory::FurriersAndFurShops => "Furriers And Fur Shops",
+            MerchantCategory::GeneralServices => "General Services",
+            MerchantCategory::GiftCardNoveltyAndSouvenirShops => "Gift Card Novelty And Souvenir Shops",
+            MerchantCategory::GlassPaintAndWallpaperStores => "Glass Paint And Wallpaper Stores",
+            MerchantCategory::GlasswareCrystalStores => "Glassware Crystal Stores",
+            MerchantCategory::GolfCoursesPublic => "Golf Courses Public",
+            MerchantCategory::GovernmentServices => "Government Services",
+            MerchantCategory::GroceryStoresSupermarkets => "Grocery Stores Supermarkets",
+            MerchantCategory::HardwareEquipmentAndSupplies => "Hardware Equipment And Supplies",
+            MerchantCategory::HardwareStores => "Hardware Stores",
+            MerchantCategory::HealthAndBeautySpas => "Health And Beauty Spas",
+            MerchantCategory::HearingAidsSalesAndSupplies => "Hearing Aids Sales And Supplies",
+            MerchantCategory::HeatingPlumbingAC => "Heating Plumbing AC",
+            MerchantCategory::HobbyToyAndGameShops => "Hobby Toy And Game Shops",
+            MerchantCategory::HomeSupplyWarehouseStores => "Home Supply Warehouse Stores",
+            MerchantCategory::Hospitals => "Hospitals",
+            MerchantCategory::HotelsMotelsAndResorts => "Hotels Motels And Resorts",
+            MerchantCategory::HouseholdApplianceStores => "Household Appliance Stores",
+            MerchantCategory::IndustrialSupplies => "Industrial Supplies",
+            MerchantCategory::InformationRetrievalServices => "Information Retrieval Services",
+            MerchantCategory::InsuranceDefault => "Insurance Default",
+            MerchantCategory::InsuranceUnderwritingPremiums => "Insurance Underwriting Premiums",
+            MerchantCategory::IntraCompanyPurchases => "Intra Company Purchases",
+            MerchantCategory::JewelryStoresWatchesClocksAndSilverwareStores => "Jewelry Stores Watches Clocks And Silverware Stores",
+            MerchantCategory::LandscapingServices => "Landscaping Services",
+            MerchantCategory::Laundries => "Laundries",
+            MerchantCategory::LaundryCleaningServices => "Laundry Cleaning Services",
+            MerchantCategory::LegalServicesAttorneys => "Legal Services Attorneys",
+            MerchantCategory::LuggageAndLeatherGoodsStores => "Luggage And Leather Goods Stores",
+            MerchantCategory::LumberBuildingMaterialsStores => "Lumber Building Materials Stores",
+            MerchantCategory::ManualCashDisburse => "Manual Cash Disburse",
+            MerchantCategory::MarinasServiceAndSupplies => "Marinas Service And Supplies",
+            MerchantCategory::MasonryStoneworkAndPlaster => "Masonry Stonework And Plaster",
+            MerchantCategory::MassageParlors => "Massage Parlors",
+            MerchantCategory::MedicalAndDentalLabs => "Medical And Dental Labs",
+            MerchantCategory::MedicalDentalOphthalmicAndHospitalEquipmentAndSupplies => "Medical Dental Ophthalmic And Hospital Equipment And Supplies",
+            MerchantCategory::MedicalServices => "Medical Services",
+            MerchantCategory::MembershipOrganizations => "Membership Organizations",
+            MerchantCategory::MensAndBoysClothingAndAccessoriesStores => "Mens And Boys Clothing And Accessories Stores",
+            MerchantCategory::MensWomensClothingStores => "Mens Womens Clothing Stores",
+            MerchantCategory::MetalServiceCenters => "Metal Service Centers",
+            MerchantCategory::Miscellaneous => "Miscellaneous",
+            MerchantCategory::MiscellaneousApparelAndAccessoryShops => "Miscellaneous Apparel And Accessory Shops",
+            MerchantCategory::MiscellaneousAutoDealers => "Miscellaneous Auto Dealers",
+            MerchantCategory::MiscellaneousBusinessServices => "Miscellaneous Business Services",
+            MerchantCategory::MiscellaneousFoodStores => "Miscellaneous Food Stores",
+            MerchantCategory::MiscellaneousGeneralMerchandise => "Miscellaneous General Merchandise",
+            MerchantCategory::MiscellaneousGeneralServices => "Miscellaneous General Services",
+            MerchantCategory::MiscellaneousHomeFurnishingSpecialtyStores => "Miscellaneous Home Furnishing Specialty Stores",
+            MerchantCategory::MiscellaneousPublishingAndPrinting => "Miscellaneous Publishing And Printing",
+            MerchantCategory::MiscellaneousRecreationServices => "Miscellaneous Recreation Services",
+            MerchantCategory::MiscellaneousRepairShops => "Miscellaneous Repair Shops",
+            MerchantCategory::MiscellaneousSpecialtyRetail => "Miscellaneous Specialty Retail",
+            MerchantCategory::MobileHomeDealers => "Mobile Home Dealers",
+            MerchantCategory::MotionPictureTheaters => "Motion Picture Theaters",
+            MerchantCategory::MotorFreightCarriersAndTrucking => "Motor Freight Carriers And Trucking",
+            MerchantCategory::MotorHomesDealers => "Motor Homes Dealers",
+            MerchantCategory::MotorVehicleSuppliesAndNewParts => "Motor Vehicle Supplies And New Parts",
+            MerchantCategory::MotorcycleShopsAndDealers => "Motorcycle Shops And Dealers",
+            MerchantCategory::MotorcycleShopsDealers => "Motorcycle Shops Dealers",
+            MerchantCategory::MusicStoresMusicalInstrumentsPianosAndSheetMusic => "Music Stores Musical Instruments Pianos And Sheet Music",
+            MerchantCategory::NewsDealersAndNewsstands => "News Dealers And Newsstands",
+            MerchantCategory::NonFiMoneyOrders => "Non Fi Money Orders",
+            MerchantCategory::NonFiStoredValueCardPurchaseLoad => "Non Fi Stored Value Card Purchase Load",
+            MerchantCategory::NondurableGoods => "Nondurable Goods",
+            MerchantCategory::NurseriesLawnAndGardenSupplyStores => "Nurseries Lawn And Garden Supply Stores",
+            MerchantCategory::NursingPersonalCare => "Nursing Personal Care",
+            MerchantCategory::OfficeAndCommercialFurniture => "Office And Commercial Furniture",
+            MerchantCategory::OpticiansEyeglasses => "Opticians Eyeglasses",
+            MerchantCategory::OptometristsOphthalmologist => "Optometrists Ophthalmologist",
+            MerchantCategory::OrthopedicGoodsProstheticDevices => "Orthopedic Goods Prosthetic Devices",
+            MerchantCategory::Osteopaths => "Osteopaths",
+            MerchantCategory::PackageStoresBeerWineAndLiquor => "Package Stores Beer Wine And Liquor",
+            MerchantCategory::PaintsVarnishesAndSupplies => "Paints Varnishes And Supplies",
+            MerchantCategory::ParkingLotsGarages => "Parking Lots Garages",
+            MerchantCategory::PassengerRailways => "Passenger Railways",
+            MerchantCategory::PawnShops => "Pawn Shops",
+            MerchantCategory::PetShopsPetFoodAndSupplies => "Pet Shops Pet Food And Supplies",
+            MerchantCategory::PetroleumAndPetroleumProducts => "Petroleum And Petroleum Products",
+            MerchantCategory::PhotoDeveloping => "Photo Developing",
+            MerchantCategory::PhotographicPhotocopyMicrofilmEquipmentAndSupplies => "Photographic Photocopy Microfilm Equipment And Supplies",
+            MerchantCategory::PhotographicStudios => "Photographic Studios",
+            MerchantCategory::PictureVideoProduction => "Picture Video Production",
+            MerchantCategory::PieceGoodsNotionsAndOtherDryGoods => "Piece Goods Notions And Other Dry Goods",
+            MerchantCategory::PlumbingHeatingEquipmentAndSupplies => "Plumbing Heating Equipment And Supplies",
+            MerchantCategory::PoliticalOrganizations => "Political Organizations",
+            MerchantCategory::PostalServicesGovernmentOnly => "Postal Services Government Only",
+            MerchantCategory::PreciousStonesAndMetalsWatchesAndJewelry => "Precious Stones And Metals Watches And Jewelry",
+            MerchantCategory::ProfessionalServices => "Professional Services",
+            MerchantCategory::PublicWarehousingAndStorage => "Public Warehousing And Storage",
+            MerchantCategory::QuickCopyReproAndBlueprint => "Quick Copy Repro And Blueprint",
+            MerchantCategory::Railroads => "Railroads",
+            MerchantCategory::RealEstateAgentsAndManagersRentals => "Real Estate Agents And Managers Rentals",
+            MerchantCategory::RecordStores => "Record Stores",
+            MerchantCategory::RecreationalVehicleRentals => "Recreational Vehicle Rentals",
+            MerchantCategory::ReligiousGoodsStores => "Religious Goods Stores",
+            MerchantCategory::ReligiousOrganizations => "Religious Organizations",
+            MerchantCategory::RoofingSidingSheetMetal => "Roofing Siding Sheet Metal",
+            MerchantCategory::SecretarialSupportServices => "Secretarial Support Services",
+            MerchantCategory::SecurityBrokersDealers => "Security Brokers Dealers",
+            MerchantCategory::ServiceStations => "Service Stations",
+            MerchantCategory::SewingNeedleworkFabricAndPieceGoodsStores => "Sewing Needlework Fabric And Piece Goods Stores",
+            MerchantCategory::ShoeRepairHatCleaning => "Shoe Repair Hat Cleaning",
+            MerchantCategory::ShoeStores => "Shoe Stores",
+            MerchantCategory::SmallApplianceRepair => "Small Appliance Repair",
+            MerchantCategory::SnowmobileDealers => "Snowmobile Dealers",
+            MerchantCategory::SpecialTradeServices => "Special Trade Services",
+            MerchantCategory::SpecialtyCleaning => "Specialty Cleaning",
+            MerchantCategory::SportingGoodsStores => "Sporting Goods Stores",
+            MerchantCategory::SportingRecreationCamps => "Sporting Recreation Camps",
+            MerchantCategory::SportsAndRidingApparelStores => "Sports And Riding Apparel Stores",
+            MerchantCategory::SportsClubsFields => "Sports Clubs Fields",
+            MerchantCategory::StampAndCoinStores => "Stamp And Coin Stores",
+            MerchantCategory::StationaryOfficeSuppliesPrintingAndWritingPaper => "Stationary Office Supplies Printing And Writing Paper",
+            MerchantCategory::StationeryStoresOfficeAndSchoolSupplyStores => "Stationery Stores Office And School Supply Stores",
+            MerchantCategory::SwimmingPoolsSales => "Swimming Pools Sales",
+            MerchantCategory::TUiTravelGermany => "TUI Travel Germany",
+            MerchantCategory::TailorsAlterations => "Tailors Alterations",
+            MerchantCategory::TaxPaymentsGovernmentAgencies => "Tax Payments Government Agencies",
+            MerchantCategory::TaxPreparationServices => "Tax Preparation Services",
+            MerchantCategory::TaxicabsLimousines => "Taxicabs Limousines",
+            MerchantCategory::TelecommunicationEquipmentAndTelephoneSales => "Telecommunication Equipment And Telephone Sales",
+            MerchantCategory::TelecommunicationServices => "Telecommunication Services",
+            MerchantCategory::TelegraphServices => "Telegraph Services",
+            MerchantCategory::TentAndAwningShops => "Tent And Awning Shops",
+            MerchantCategory::TestingLaboratories => "Testing Laboratories",
+            MerchantCategory::TheatricalTicketAgencies => "Theatrical Ticket Agencies",
+            MerchantCategory::Timeshares => "Timeshares",
+            MerchantCategory::TireRetreadingAndRepair => "Tire Retreading And Repair",
+            MerchantCategory::TollsBridgeFees => "Tolls Bridge Fees",
+            MerchantCategory::TouristAttractionsAndExhibits => "Tourist Attractions And Exhibits",
+            MerchantCategory::TowingServices => "Towing Services",
+            MerchantCategory::TrailerParksCampgrounds => "Trailer Parks Campgrounds",
+            MerchantCategory::TransportationServices => "Transportation Services",
+            MerchantCategory::TravelAgenciesTourOperators => "Travel Agencies Tour Operators",
+            MerchantCategory::TruckStopIteration => "Truck Stop Iteration",
+            MerchantCategory::TruckUtilityTrailerRentals => "Truck Utility Trailer Rentals",
+            MerchantCategory::TypesettingPlateMakingAndRelatedServices => "Typesetting Plate Making And Related Services",
+            MerchantCategory::TypewriterStores => "Typewriter Stores",
+            MerchantCategory::USFederalGovernmentAgenciesOrDepartments => "US Federal Government Agencies Or Departments",
+            MerchantCategory::UniformsCommercialClothing => "Uniforms Commercial Clothing",
+            MerchantCategory::UsedMerchandiseAndSecondhandStores => "Used Merchandise And Secondhand Stores",
+            MerchantCategory::Utilities => "Utilities",
+            MerchantCategory::VarietyStores => "Variety Stores",
+            MerchantCategory::VeterinaryServices => "Veterinary Services",
+            MerchantCategory::VideoAmusementGameSupplies => "Video Amusement Game Supplies",
+            MerchantCategory::VideoGameArcades => "Video Game Arcades",
+            MerchantCategory::VideoTapeRentalStores => "Video Tape Rental Stores",
+            MerchantCategory::VocationalTradeSchools => "Vocational Trade Schools",
+            MerchantCategory::WatchJewelryRepair => "Watch Jewelry Repair",
+            MerchantCategory::WeldingRepair => "Welding Repair",
+            MerchantCategory::WholesaleClubs => "Wholesale Clubs",
+            MerchantCategory::WigAndToupeeStores => "Wig And Toupee Stores",
+            MerchantCategory::WiresMoneyOrders => "Wires Money Orders",
+            MerchantCategory::WomensAccessoryAndSpecialtyShops => "Womens Accessory And Specialty Shops",
+            MerchantCategory::WomensReadyToWearStores => "Womens Ready To Wear Stores",
+            MerchantCategory::WreckingAndSalvageYards => "Wrecking And Salvage Yards",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn merchant(name: &str, city: &str, state: &str, country: &str) -> MerchantData {
+        MerchantData {
+            network_id: "1234567890".into(),
+            category: MerchantCategory::Bakeries,
+            name: Some(name.into()),
+            city: Some(city.into()),
+            state: Some(state.into()),
+            country: Some(country.into()),
+            postal_code: Some("94103".into()),
+        }
+    }
+
+    #[test]
+    fn normalized_trims_and_title_cases_name_and_city() {
+        let data = merchant("  acme  CORP ", " san francisco ", "ca", "us");
+        let normalized = data.normalized();
+        assert_eq!(normalized.name.as_deref(), Some("Acme Corp"));
+        assert_eq!(normalized.city.as_deref(), Some("San Francisco"));
+        assert_eq!(normalized.state.as_deref(), Some("CA"));
+        assert_eq!(normalized.country.as_deref(), Some("US"));
+    }
+
+    #[test]
+    fn normalized_does_not_reduce_full_names_to_iso_codes() {
+        let data = merchant("Acme Corp", "San Francisco", "California", "United States");
+        let normalized = data.normalized();
+        assert_eq!(normalized.state.as_deref(), Some("CALIFORNIA"));
+        assert_eq!(normalized.country.as_deref(), Some("UNITED STATES"));
+    }
+
+    #[test]
+    fn merchant_key_is_stable_across_name_casing_and_whitespace() {
+        let a = merchant("Acme Corp", "San Francisco", "CA", "US");
+        let b = merchant("  ACME corp  ", "san francisco", "ca", "us");
+        assert_eq!(a.merchant_key(), b.merchant_key());
+    }
+
+    #[test]
+    fn merchant_key_differs_for_different_merchants() {
+        let a = merchant("Acme Corp", "San Francisco", "CA", "US");
+        let b = merchant("Widgets Inc", "San Francisco", "CA", "US");
+        assert_ne!(a.merchant_key(), b.merchant_key());
+    }
+}
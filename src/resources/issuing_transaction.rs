@@ -0,0 +1,374 @@
+// ======================================
+// This file was automatically generated.
+// ======================================
+
+use crate::config::{Client, Response};
+use crate::ids::{IssuingCardId, IssuingTransactionId};
+use crate::params::{Expand, Expandable, List, Metadata, Object, RangeQuery, Timestamp};
+use crate::resources::{
+    BalanceTransaction, Currency, IssuingAuthorization, IssuingCard, IssuingCardholder,
+    IssuingDispute,
+};
+use serde_derive::{Deserialize, Serialize};
+
+/// The resource representing a Stripe "IssuingTransaction".
+///
+/// For more details see [https://stripe.com/docs/api/issuing/transactions/object](https://stripe.com/docs/api/issuing/transactions/object).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct IssuingTransaction {
+    /// Unique identifier for the object.
+    pub id: IssuingTransactionId,
+
+    /// The transaction amount, which will be reflected in your balance.
+    ///
+    /// This amount is in your currency and in the [smallest currency unit](https://stripe.com/docs/currencies#zero-decimal).
+    pub amount: i64,
+
+    /// The `Authorization` object that led to this transaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorization: Option<Expandable<IssuingAuthorization>>,
+
+    /// ID of the balance transaction associated with this transaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub balance_transaction: Option<Expandable<BalanceTransaction>>,
+
+    /// The card used to make this transaction.
+    pub card: Expandable<IssuingCard>,
+
+    /// The cardholder to whom this transaction belongs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cardholder: Option<Expandable<IssuingCardholder>>,
+
+    /// Time at which the object was created.
+    ///
+    /// Measured in seconds since the Unix epoch.
+    pub created: Timestamp,
+
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    pub currency: Currency,
+
+    /// If you've disputed the transaction, the ID of the dispute.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dispute: Option<Expandable<IssuingDispute>>,
+
+    /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+    pub livemode: bool,
+
+    /// Set of key-value pairs that you can attach to an object.
+    pub metadata: Metadata,
+
+    /// Additional purchase information that is optionally provided by the merchant.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub purchase_details: Option<PurchaseDetails>,
+
+    /// The nature of the transaction.
+    #[serde(rename = "type")]
+    pub type_: IssuingTransactionType,
+}
+
+impl IssuingTransaction {
+    /// Returns a list of Issuing `Transaction` objects.
+    pub fn list(
+        client: &Client,
+        params: ListIssuingTransactions<'_>,
+    ) -> Response<List<IssuingTransaction>> {
+        client.get_query("/issuing/transactions", &params)
+    }
+
+    /// Retrieves an Issuing `Transaction` object.
+    pub fn retrieve(
+        client: &Client,
+        id: &IssuingTransactionId,
+        expand: &[&str],
+    ) -> Response<IssuingTransaction> {
+        client.get_query(&format!("/issuing/transactions/{}", id), &Expand { expand })
+    }
+
+    /// Updates the specified Issuing `Transaction` object by setting the values of the parameters passed.
+    ///
+    /// Any parameters not provided will be left unchanged.
+    pub fn update(
+        client: &Client,
+        id: &IssuingTransactionId,
+        params: UpdateIssuingTransaction<'_>,
+    ) -> Response<IssuingTransaction> {
+        client.post_form(&format!("/issuing/transactions/{}", id), &params)
+    }
+
+    /// Allows the user to capture an arbitrary amount, also known as a forced capture.
+    pub fn create_force_capture(
+        client: &Client,
+        params: CreateForceCapture<'_>,
+    ) -> Response<IssuingTransaction> {
+        client.post_form("/test_helpers/issuing/transactions/create_force_capture", &params)
+    }
+
+    /// Allows the user to refund an arbitrary amount, also known as a unlinked refund.
+    pub fn create_unlinked_refund(
+        client: &Client,
+        params: CreateUnlinkedRefund<'_>,
+    ) -> Response<IssuingTransaction> {
+        client.post_form("/test_helpers/issuing/transactions/create_unlinked_refund", &params)
+    }
+}
+
+impl Object for IssuingTransaction {
+    type Id = IssuingTransactionId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "issuing.transaction"
+    }
+}
+
+/// An enum representing the possible values of an `IssuingTransaction`'s `type` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum IssuingTransactionType {
+    Capture,
+    Refund,
+}
+
+/// Additional purchase information that is optionally provided by the merchant.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PurchaseDetails {
+    /// Information about the flight that was purchased with this transaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flight: Option<PurchaseDetailsFlight>,
+
+    /// Information about fuel that was purchased with this transaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fuel: Option<PurchaseDetailsFuel>,
+
+    /// Information about lodging that was purchased with this transaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lodging: Option<PurchaseDetailsLodging>,
+
+    /// The line items in the purchase.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receipt: Option<Vec<PurchaseDetailsReceiptItem>>,
+
+    /// A merchant-supplied reference number, such as an order or ticket number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PurchaseDetailsFlight {
+    /// The time that the flight departed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub departure_at: Option<Timestamp>,
+
+    /// The name of the passenger.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub passenger_name: Option<String>,
+
+    /// Whether the ticket is refundable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refundable: Option<bool>,
+
+    /// The legs of the trip.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub segments: Option<Vec<PurchaseDetailsFlightSegment>>,
+
+    /// The travel agency that issued the ticket.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub travel_agency: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PurchaseDetailsFlightSegment {
+    /// The three-letter IATA airport code of the flight's destination.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arrival_airport_code: Option<String>,
+
+    /// The airline carrier code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub carrier: Option<String>,
+
+    /// The three-letter IATA airport code that the flight departed from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub departure_airport_code: Option<String>,
+
+    /// The flight number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flight_number: Option<String>,
+
+    /// The flight's service class.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_class: Option<String>,
+
+    /// Whether a stopover is allowed on this flight.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stopover_allowed: Option<bool>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PurchaseDetailsFuel {
+    /// The type of fuel that was purchased.
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_: Option<String>,
+
+    /// The units for `volume_decimal`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
+
+    /// The cost in cents per each unit of fuel, represented as a decimal string with at most 12 decimal places.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_cost_decimal: Option<String>,
+
+    /// The volume of the fuel that was purchased, represented as a decimal string with at most 12 decimal places.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub volume_decimal: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PurchaseDetailsLodging {
+    /// The time of checking into the lodging.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub check_in_at: Option<Timestamp>,
+
+    /// The number of nights stayed at the lodging.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nights: Option<i64>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PurchaseDetailsReceiptItem {
+    /// The description of the item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// The quantity of the item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<f64>,
+
+    /// The total for this line item in cents.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<i64>,
+
+    /// The unit cost of the item in cents.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_cost: Option<i64>,
+}
+
+/// The parameters for `IssuingTransaction::update`.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct UpdateIssuingTransaction<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+impl<'a> UpdateIssuingTransaction<'a> {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+/// The parameters for `IssuingTransaction::list`.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ListIssuingTransactions<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card: Option<IssuingCardId>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+
+    /// A cursor for use in pagination.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a IssuingTransactionId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a IssuingTransactionId>,
+
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_: Option<IssuingTransactionType>,
+}
+
+impl<'a> ListIssuingTransactions<'a> {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+/// The parameters for `IssuingTransaction::create_force_capture`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateForceCapture<'a> {
+    pub amount: i64,
+
+    pub card: &'a IssuingCardId,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merchant_data: Option<crate::resources::MerchantData>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub purchase_details: Option<PurchaseDetails>,
+}
+
+impl<'a> CreateForceCapture<'a> {
+    pub fn new(amount: i64, card: &'a IssuingCardId) -> Self {
+        CreateForceCapture {
+            amount,
+            card,
+            currency: Default::default(),
+            expand: Default::default(),
+            merchant_data: Default::default(),
+            purchase_details: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `IssuingTransaction::create_unlinked_refund`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateUnlinkedRefund<'a> {
+    pub amount: i64,
+
+    pub card: &'a IssuingCardId,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merchant_data: Option<crate::resources::MerchantData>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub purchase_details: Option<PurchaseDetails>,
+}
+
+impl<'a> CreateUnlinkedRefund<'a> {
+    pub fn new(amount: i64, card: &'a IssuingCardId) -> Self {
+        CreateUnlinkedRefund {
+            amount,
+            card,
+            currency: Default::default(),
+            expand: Default::default(),
+            merchant_data: Default::default(),
+            purchase_details: Default::default(),
+        }
+    }
+}
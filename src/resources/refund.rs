@@ -52,7 +52,7 @@ pub struct Refund {
     ///
     /// Possible values are `lost_or_stolen_card`, `expired_or_canceled_card`, or `unknown`.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub failure_reason: Option<String>,
+    pub failure_reason: Option<RefundFailureReason>,
 
     /// Set of key-value pairs that you can attach to an object.
     ///
@@ -63,7 +63,7 @@ pub struct Refund {
     ///
     /// If set, possible values are `duplicate`, `fraudulent`, and `requested_by_customer`.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reason: Option<String>,
+    pub reason: Option<RefundReason>,
 
     /// This is the transaction number that appears on email receipts sent for this refund.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -82,7 +82,7 @@ pub struct Refund {
     /// For other types of refunds, it can be `pending`, `succeeded`, `failed`, or `canceled`.
     /// Refer to our [refunds](https://stripe.com/docs/refunds#failed-refunds) documentation for more details.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<RefundStatus>,
 
     /// If the accompanying transfer was reversed, the transfer reversal object.
     ///
@@ -105,6 +105,17 @@ impl Refund {
         client.post_form("/refunds", &params)
     }
 
+    /// Create a refund, tagged with an idempotency key so that retrying this exact request
+    /// (e.g. after a network timeout) returns the original refund instead of creating a
+    /// second one.
+    pub fn create_with_idempotency_key(
+        client: &Client,
+        params: CreateRefund<'_>,
+        idempotency_key: &str,
+    ) -> Response<Refund> {
+        client.with_idempotency_key(idempotency_key).post_form("/refunds", &params)
+    }
+
     /// Retrieves the details of an existing refund.
     pub fn retrieve(client: &Client, id: &RefundId, expand: &[&str]) -> Response<Refund> {
         client.get_query(&format!("/refunds/{}", id), &Expand { expand })
@@ -116,6 +127,34 @@ impl Refund {
     pub fn update(client: &Client, id: &RefundId, params: UpdateRefund<'_>) -> Response<Refund> {
         client.post_form(&format!("/refunds/{}", id), &params)
     }
+
+    /// Cancels a refund that has a status of `requires_action`.
+    ///
+    /// Refunds in other states cannot be canceled.
+    pub fn cancel(client: &Client, id: &RefundId) -> Response<Refund> {
+        client.post(&format!("/refunds/{}/cancel", id))
+    }
+
+    /// Fetches every refund matching `params`, automatically following pagination by setting
+    /// `starting_after` to the id of the last refund on each page until `has_more` is `false`.
+    pub fn list_all(client: &Client, mut params: ListRefunds<'_>) -> Response<Vec<Refund>> {
+        let mut refunds = Vec::new();
+        loop {
+            let page = Refund::list(client, params.clone())?;
+            let has_more = page.has_more;
+            let last_id = page.data.last().map(|refund| refund.id.clone());
+            refunds.extend(page.data);
+
+            if !has_more {
+                break;
+            }
+            match last_id {
+                Some(id) => params.starting_after = Some(id),
+                None => break,
+            }
+        }
+        Ok(refunds)
+    }
 }
 
 impl Object for Refund {
@@ -243,16 +282,23 @@ impl<'a> UpdateRefund<'a> {
 #[serde(rename_all = "snake_case")]
 pub enum RefundReason {
     Duplicate,
+    ExpiredUncapturedCharge,
     Fraudulent,
     RequestedByCustomer,
+
+    /// An unrecognized value from Stripe. Should not be used as a request parameter.
+    #[serde(other)]
+    Other,
 }
 
 impl RefundReason {
     pub fn as_str(&self) -> &'static str {
         match self {
             RefundReason::Duplicate => "duplicate",
+            RefundReason::ExpiredUncapturedCharge => "expired_uncaptured_charge",
             RefundReason::Fraudulent => "fraudulent",
             RefundReason::RequestedByCustomer => "requested_by_customer",
+            RefundReason::Other => "other",
         }
     }
 }
@@ -268,3 +314,79 @@ impl std::fmt::Display for RefundReason {
         self.as_str().fmt(f)
     }
 }
+
+/// An enum representing the possible values of a `Refund`'s `status` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum RefundStatus {
+    Canceled,
+    Failed,
+    Pending,
+    RequiresAction,
+    Succeeded,
+
+    /// An unrecognized value from Stripe. Should not be used as a request parameter.
+    #[serde(other)]
+    Other,
+}
+
+impl RefundStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RefundStatus::Canceled => "canceled",
+            RefundStatus::Failed => "failed",
+            RefundStatus::Pending => "pending",
+            RefundStatus::RequiresAction => "requires_action",
+            RefundStatus::Succeeded => "succeeded",
+            RefundStatus::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for RefundStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for RefundStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of a `Refund`'s `failure_reason` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum RefundFailureReason {
+    ExpiredOrCanceledCard,
+    LostOrStolenCard,
+    Unknown,
+
+    /// An unrecognized value from Stripe. Should not be used as a request parameter.
+    #[serde(other)]
+    Other,
+}
+
+impl RefundFailureReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RefundFailureReason::ExpiredOrCanceledCard => "expired_or_canceled_card",
+            RefundFailureReason::LostOrStolenCard => "lost_or_stolen_card",
+            RefundFailureReason::Unknown => "unknown",
+            RefundFailureReason::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for RefundFailureReason {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for RefundFailureReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
@@ -2,6 +2,8 @@ use error::Error;
 use http;
 use resources::{Address, Card};
 use params::Metadata;
+use serde::de::{self, Deserialize, Deserializer};
+use serde_json::Value;
 
 #[derive(Serialize)]
 pub struct OwnerParams<'a> {
@@ -32,16 +34,165 @@ pub struct SourceParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")] pub usage: Option<&'a str>, // (reusable, single-use)
 }
 
-#[derive(Debug, Deserialize)]
-#[serde(tag = "object")]
-pub enum Source {
-    // BitcoinReceiver(...),
+/// The status of a redirect- or receiver-based `Source`.
+///
+/// See https://stripe.com/docs/sources#statuses.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SourceStatus {
+    Canceled,
+    Chargeable,
+    Consumed,
+    Failed,
+    Pending,
+}
+
+/// Information related to the redirect flow, present when a `Source` is used to collect a
+/// redirect-based authentication.
+#[derive(Clone, Debug, Deserialize)]
+pub struct SourceRedirect {
+    /// The URL you provide to redirect the customer to after they authenticated their payment.
+    pub return_url: String,
 
-    #[serde(rename = "card")]
+    /// The status of the redirect (`pending`, `succeeded`, `failed`, or `not_required`).
+    pub status: String,
+
+    /// The URL provided to you to redirect a customer to as part of a redirect authentication flow.
+    pub url: String,
+}
+
+/// The type-specific hash for a `three_d_secure` source.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct ThreeDSecureDetails {
+    pub authenticated: Option<bool>,
+    pub card: Option<String>,
+    pub customer: Option<String>,
+}
+
+/// The type-specific hash for an `ideal` source.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct IdealDetails {
+    pub bank: Option<String>,
+    pub bic: Option<String>,
+    pub iban_last4: Option<String>,
+}
+
+/// The type-specific hash for a `sepa_debit` source.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct SepaDebitDetails {
+    pub bank_code: Option<String>,
+    pub country: Option<String>,
+    pub fingerprint: Option<String>,
+    pub last4: Option<String>,
+    pub mandate_reference: Option<String>,
+}
+
+/// The type-specific hash for a `sofort` source.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct SofortDetails {
+    pub bank_code: Option<String>,
+    pub bank_name: Option<String>,
+    pub bic: Option<String>,
+    pub country: Option<String>,
+    pub iban_last4: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ThreeDSecureSource {
+    pub id: String,
+    pub status: SourceStatus,
+    pub redirect: Option<SourceRedirect>,
+    pub three_d_secure: ThreeDSecureDetails,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct IdealSource {
+    pub id: String,
+    pub status: SourceStatus,
+    pub redirect: Option<SourceRedirect>,
+    pub ideal: IdealDetails,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct SepaDebitSource {
+    pub id: String,
+    pub status: SourceStatus,
+    pub redirect: Option<SourceRedirect>,
+    pub sepa_debit: SepaDebitDetails,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct SofortSource {
+    pub id: String,
+    pub status: SourceStatus,
+    pub redirect: Option<SourceRedirect>,
+    pub sofort: SofortDetails,
+}
+
+#[derive(Debug)]
+pub enum Source {
     Card(Card),
+    ThreeDSecure(ThreeDSecureSource),
+    Ideal(IdealSource),
+    SepaDebit(SepaDebitSource),
+    Sofort(SofortSource),
+
+    /// Any other source type not yet modeled by this crate.
+    ///
+    /// Kept as the raw JSON so callers aren't blocked on us adding explicit support.
+    Other(Value),
+}
+
+impl<'de> Deserialize<'de> for Source {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+
+        // Legacy card-as-source responses come back as a bare `Card` object (`object: "card"`).
+        if value.get("object").and_then(Value::as_str) == Some("card") {
+            return Card::deserialize(value).map(Source::Card).map_err(de::Error::custom);
+        }
+
+        match value.get("type").and_then(Value::as_str) {
+            Some("three_d_secure") => {
+                ThreeDSecureSource::deserialize(value).map(Source::ThreeDSecure).map_err(de::Error::custom)
+            }
+            Some("ideal") => {
+                IdealSource::deserialize(value).map(Source::Ideal).map_err(de::Error::custom)
+            }
+            Some("sepa_debit") => {
+                SepaDebitSource::deserialize(value).map(Source::SepaDebit).map_err(de::Error::custom)
+            }
+            Some("sofort") => {
+                SofortSource::deserialize(value).map(Source::Sofort).map_err(de::Error::custom)
+            }
+            _ => Ok(Source::Other(value)),
+        }
+    }
 }
 
 impl Source {
+    /// The current status of this source, if it is a type that has one.
+    pub fn status(&self) -> Option<SourceStatus> {
+        match self {
+            Source::Card(_) => None,
+            Source::ThreeDSecure(s) => Some(s.status),
+            Source::Ideal(s) => Some(s.status),
+            Source::SepaDebit(s) => Some(s.status),
+            Source::Sofort(s) => Some(s.status),
+            Source::Other(v) => match v.get("status").and_then(Value::as_str) {
+                Some("canceled") => Some(SourceStatus::Canceled),
+                Some("chargeable") => Some(SourceStatus::Chargeable),
+                Some("consumed") => Some(SourceStatus::Consumed),
+                Some("failed") => Some(SourceStatus::Failed),
+                Some("pending") => Some(SourceStatus::Pending),
+                _ => None,
+            },
+        }
+    }
+
     pub fn create(params: SourceParams, key: &str) -> Result<Source, Error> {
         return http::post("/sources", key, params);
     }
@@ -51,6 +202,29 @@ impl Source {
     }
 
     pub fn update(source_id: &str, params: SourceParams, key: &str) -> Result<Source, Error> {
-        return http::post(&format!("/source/{}", source_id), key, params);
+        return http::post(&format!("/sources/{}", source_id), key, params);
+    }
+
+    /// Re-fetches this source until its status leaves `pending`, for driving redirect and
+    /// receiver flows to completion.
+    ///
+    /// Performs at most `max_attempts` fetches (always at least one, even when `max_attempts`
+    /// is 0), sleeping one second between each attempt, and
+    /// returns whatever status the source is in once it stops being `pending` (or once
+    /// `max_attempts` is reached).
+    pub fn poll_until_chargeable(
+        source_id: &str,
+        key: &str,
+        max_attempts: u32,
+    ) -> Result<Source, Error> {
+        let mut attempts = 0;
+        loop {
+            let source = Source::get(source_id, key)?;
+            attempts += 1;
+            if source.status() != Some(SourceStatus::Pending) || attempts >= max_attempts {
+                return Ok(source);
+            }
+            ::std::thread::sleep(::std::time::Duration::from_secs(1));
+        }
     }
 }
@@ -3,7 +3,7 @@ use crate::ids::{CustomerId, PlanId, SubscriptionId};
 use crate::params::{Expand, Expandable, List, Metadata, Object, RangeQuery, Timestamp};
 use crate::resources::{
     Customer, Discount, Invoice, PaymentMethod, PaymentSource, Plan, SubscriptionBillingThresholds,
-    SubscriptionItem, TaxRate,
+    SubscriptionItem, TaxRate, TestClock,
 };
 use serde_derive::{Deserialize, Serialize};
 
@@ -21,6 +21,11 @@ pub struct Subscription {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub application_fee_percent: Option<f64>,
 
+    /// Settings that let you automatically collect the appropriate tax rate based on the
+    /// customer's location.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub automatic_tax: Option<AutomaticTax>,
+
     /// Either `charge_automatically`, or `send_invoice`.
     ///
     /// When charging automatically, Stripe will attempt to pay this subscription at the end of the cycle using the default source attached to the customer.
@@ -39,6 +44,10 @@ pub struct Subscription {
     /// You can use this attribute to determine whether a subscription that has a status of active is scheduled to be canceled at the end of the current period.
     pub cancel_at_period_end: bool,
 
+    /// Details about why this subscription was cancelled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancellation_details: Option<CancellationDetails>,
+
     /// If the subscription has been canceled, the date of that cancellation.
     ///
     /// If the subscription was canceled with `cancel_at_period_end`, `canceled_at` will still reflect the date of the initial cancellation request, not the end of the subscription period when the subscription is automatically moved to a canceled state.
@@ -112,6 +121,10 @@ pub struct Subscription {
     /// This can be useful for storing additional information about the object in a structured format.
     pub metadata: Metadata,
 
+    /// If specified, payment collection for this subscription is paused.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pause_collection: Option<PauseCollection>,
+
     /// Hash describing the plan the customer is subscribed to.
     ///
     /// Only set if the subscription contains a single plan.
@@ -155,6 +168,10 @@ pub struct Subscription {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tax_percent: Option<f64>,
 
+    /// ID of the test clock this subscription belongs to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub test_clock: Option<Expandable<TestClock>>,
+
     /// If the subscription has a trial, the end of that trial.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub trial_end: Option<Timestamp>,
@@ -209,6 +226,17 @@ impl Subscription {
     ) -> Response<List<Subscription>> {
         client.get_query("/subscriptions", &params)
     }
+
+    /// Previews the upcoming invoice that the proposed subscription item changes would produce,
+    /// without actually updating the subscription.
+    ///
+    /// For more details see https://stripe.com/docs/api#upcoming_invoice.
+    pub fn upcoming_invoice(
+        client: &Client,
+        params: UpcomingInvoiceParams<'_>,
+    ) -> Response<Invoice> {
+        client.get_query("/invoices/upcoming", &params)
+    }
 }
 
 impl Object for Subscription {
@@ -225,6 +253,27 @@ impl Object for Subscription {
 pub struct CancelParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub at_period_end: Option<bool>,
+
+    /// A timestamp at which the subscription should cancel.
+    ///
+    /// If set, `at_period_end` must not be set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancel_at: Option<Timestamp>,
+
+    /// Details about why this subscription was cancelled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancellation_details: Option<CancellationDetailsParams>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CancellationDetailsParams {
+    /// Additional comments about why the user canceled the subscription.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+
+    /// The customer's reason for canceling the subscription.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub feedback: Option<CancellationFeedback>,
 }
 
 #[derive(Clone, Serialize, Debug)]
@@ -234,6 +283,64 @@ pub struct ItemParams<'a> {
     pub quantity: Option<u64>,
 }
 
+/// An enum representing the possible values of a `SubscriptionParams`'s `proration_behavior` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ProrationBehavior {
+    AlwaysInvoice,
+    CreateProrations,
+    None,
+}
+
+impl std::default::Default for ProrationBehavior {
+    fn default() -> Self {
+        Self::CreateProrations
+    }
+}
+
+/// The parameters for `Subscription::upcoming_invoice`.
+#[derive(Clone, Debug, Serialize)]
+pub struct UpcomingInvoiceParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<&'a str>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription: Option<&'a str>,
+
+    /// The subscription items to preview the invoice with, in place of the subscription's
+    /// existing items.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_items: Option<Vec<ItemParams<'a>>>,
+
+    /// If previewing an update to a subscription, this decides whether the preview will show
+    /// the result of applying proration to the scheduled change as of the given
+    /// `subscription_proration_date`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_proration_behavior: Option<ProrationBehavior>,
+
+    /// If previewing an update to a subscription, the timestamp used for proration calculations
+    /// instead of the current time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_proration_date: Option<Timestamp>,
+}
+
+impl<'a> UpcomingInvoiceParams<'a> {
+    pub fn new() -> Self {
+        UpcomingInvoiceParams {
+            customer: Default::default(),
+            expand: Default::default(),
+            subscription: Default::default(),
+            subscription_items: Default::default(),
+            subscription_proration_behavior: Default::default(),
+            subscription_proration_date: Default::default(),
+        }
+    }
+}
+
 /// The set of parameters that can be used when creating or updating a subscription.
 ///
 /// For more details see https://stripe.com/docs/api#create_subscription and https://stripe.com/docs/api#update_subscription.
@@ -244,15 +351,19 @@ pub struct SubscriptionParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub application_fee_percent: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub automatic_tax: Option<AutomaticTax>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub coupon: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub items: Option<Vec<ItemParams<'a>>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<Metadata>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub pause_collection: Option<PauseCollectionParam<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub plan: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub prorate: Option<bool>,
+    pub proration_behavior: Option<ProrationBehavior>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub proration_date: Option<Timestamp>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -386,3 +497,88 @@ pub enum TrialEnd<'a> {
     Timestamp(Timestamp),
     Special(&'a str),
 }
+
+/// Details about why a subscription was cancelled.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CancellationDetails {
+    /// Additional comments about why the user canceled the subscription, if the cancellation
+    /// was performed by the customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+
+    /// The customer's reason for canceling the subscription, if the cancellation was performed
+    /// by the customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub feedback: Option<CancellationFeedback>,
+}
+
+/// An enum representing the possible values of a `CancellationDetails`'s `feedback` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CancellationFeedback {
+    CustomerService,
+    LowQuality,
+    MissingFeatures,
+    Other,
+    SwitchedService,
+    TooComplex,
+    TooExpensive,
+    Unused,
+}
+
+/// Settings that let you automatically collect the appropriate tax rate based on the
+/// customer's location.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AutomaticTax {
+    /// Whether Stripe automatically computes tax on this subscription's invoices.
+    pub enabled: bool,
+
+    /// The status of the most recent automated tax calculation for this subscription.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<AutomaticTaxStatus>,
+}
+
+/// An enum representing the possible values of an `AutomaticTax`'s `status` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum AutomaticTaxStatus {
+    Complete,
+    Failed,
+    RequiresLocationInputs,
+}
+
+/// Information about the current pause collection behavior, if collection has been paused.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PauseCollection {
+    /// The payment collection behavior for this subscription while paused.
+    pub behavior: PauseCollectionBehavior,
+
+    /// The time after which the subscription will resume collecting payments.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resumes_at: Option<Timestamp>,
+}
+
+/// An enum representing the possible values of a `PauseCollection`'s `behavior` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PauseCollectionBehavior {
+    KeepAsDraft,
+    MarkUncollectible,
+    Void,
+}
+
+impl std::default::Default for PauseCollectionBehavior {
+    fn default() -> Self {
+        Self::MarkUncollectible
+    }
+}
+
+/// The set of parameters that can be used to pause or resume collection on a subscription.
+///
+/// Pass [`PauseCollectionParam::Resume`] with an empty string to resume collection.
+#[derive(Clone, Debug, Serialize)]
+#[serde(untagged)]
+pub enum PauseCollectionParam<'a> {
+    Pause(PauseCollection),
+    Resume(&'a str),
+}
@@ -0,0 +1,421 @@
+// ======================================
+// This file was automatically generated.
+// ======================================
+
+use crate::config::{Client, Response};
+use crate::ids::{CustomerId, SubscriptionId, SubscriptionScheduleId};
+use crate::params::{Expand, Expandable, List, Metadata, Object, Timestamp};
+use crate::resources::{
+    Customer, ItemParams, PaymentSource, Subscription, SubscriptionBilling,
+    SubscriptionBillingThresholds, TaxRate,
+};
+use serde_derive::{Deserialize, Serialize};
+
+/// The resource representing a Stripe "SubscriptionSchedule".
+///
+/// For more details see [https://stripe.com/docs/api/subscription_schedules/object](https://stripe.com/docs/api/subscription_schedules/object).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SubscriptionSchedule {
+    /// Unique identifier for the object.
+    pub id: SubscriptionScheduleId,
+
+    /// Time at which the subscription schedule was canceled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub canceled_at: Option<Timestamp>,
+
+    /// Time at which the subscription schedule was completed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<Timestamp>,
+
+    /// Time at which the object was created.
+    ///
+    /// Measured in seconds since the Unix epoch.
+    pub created: Timestamp,
+
+    /// The phase that the subscription schedule is currently in, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_phase: Option<SubscriptionScheduleCurrentPhase>,
+
+    /// ID of the customer who owns the subscription schedule.
+    pub customer: Expandable<Customer>,
+
+    pub default_settings: SubscriptionScheduleDefaultSettings,
+
+    /// Behavior of the subscription schedule once all the phases are complete.
+    pub end_behavior: SubscriptionScheduleEndBehavior,
+
+    /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+    pub livemode: bool,
+
+    /// Set of key-value pairs that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    pub metadata: Metadata,
+
+    /// Configuration for the phases of the subscription schedule, ordered chronologically.
+    pub phases: Vec<SchedulePhase>,
+
+    /// ID of the subscription once managed by the subscription schedule, if it has been released.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub released_subscription: Option<String>,
+
+    /// The present status of the subscription schedule.
+    ///
+    /// Possible values are `not_started`, `active`, `completed`, `released`, and `canceled`.
+    pub status: SubscriptionScheduleStatus,
+
+    /// ID of the subscription managed by the subscription schedule.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription: Option<Expandable<Subscription>>,
+}
+
+impl SubscriptionSchedule {
+    /// Retrieves the list of your subscription schedules.
+    pub fn list(
+        client: &Client,
+        params: ListSubscriptionSchedules<'_>,
+    ) -> Response<List<SubscriptionSchedule>> {
+        client.get_query("/subscription_schedules", &params)
+    }
+
+    /// Creates a new subscription schedule object.
+    pub fn create(
+        client: &Client,
+        params: CreateSubscriptionSchedule<'_>,
+    ) -> Response<SubscriptionSchedule> {
+        client.post_form("/subscription_schedules", &params)
+    }
+
+    /// Creates a new subscription schedule object from an existing subscription, so it can be
+    /// managed through future phases.
+    pub fn create_from_subscription(
+        client: &Client,
+        params: CreateSubscriptionScheduleFromSubscription<'_>,
+    ) -> Response<SubscriptionSchedule> {
+        client.post_form("/subscription_schedules", &params)
+    }
+
+    /// Retrieves the details of an existing subscription schedule.
+    pub fn retrieve(
+        client: &Client,
+        id: &SubscriptionScheduleId,
+        expand: &[&str],
+    ) -> Response<SubscriptionSchedule> {
+        client.get_query(&format!("/subscription_schedules/{}", id), &Expand { expand })
+    }
+
+    /// Updates an existing subscription schedule.
+    pub fn update(
+        client: &Client,
+        id: &SubscriptionScheduleId,
+        params: UpdateSubscriptionSchedule<'_>,
+    ) -> Response<SubscriptionSchedule> {
+        client.post_form(&format!("/subscription_schedules/{}", id), &params)
+    }
+
+    /// Releases the subscription schedule immediately, which will stop scheduling of its phases,
+    /// but leave any existing subscription unaltered.
+    ///
+    /// A schedule can only be released if its status is `not_started` or `active`.
+    pub fn release(
+        client: &Client,
+        id: &SubscriptionScheduleId,
+    ) -> Response<SubscriptionSchedule> {
+        client.post(&format!("/subscription_schedules/{}/release", id))
+    }
+
+    /// Cancels a subscription schedule and its associated subscription immediately, if the
+    /// subscription schedule is `active`.
+    pub fn cancel(
+        client: &Client,
+        id: &SubscriptionScheduleId,
+        params: CancelSubscriptionSchedule,
+    ) -> Response<SubscriptionSchedule> {
+        client.post_form(&format!("/subscription_schedules/{}/cancel", id), &params)
+    }
+}
+
+impl Object for SubscriptionSchedule {
+    type Id = SubscriptionScheduleId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "subscription_schedule"
+    }
+}
+
+/// The phase that a `SubscriptionSchedule` is currently in, if any.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SubscriptionScheduleCurrentPhase {
+    /// The end of this phase of the subscription schedule.
+    pub end_date: Timestamp,
+
+    /// The start of this phase of the subscription schedule.
+    pub start_date: Timestamp,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SubscriptionScheduleDefaultSettings {
+    /// A non-negative decimal between 0 and 100, with at most two decimal places, to be applied
+    /// to invoices generated during phases for which this is unset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_fee_percent: Option<f64>,
+
+    /// Either `charge_automatically`, or `send_invoice`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing: Option<SubscriptionBilling>,
+
+    /// Define thresholds at which an invoice will be sent, and the subscription advanced to a
+    /// new billing period, for phases for which this is unset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing_thresholds: Option<SubscriptionBillingThresholds>,
+
+    /// The default payment source to use for phases for which this is unset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_source: Option<PaymentSource>,
+
+    /// The tax rates that apply to any subscription item that does not have `tax_rates` set,
+    /// for phases for which this is unset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_tax_rates: Option<Vec<TaxRate>>,
+}
+
+/// A phase of billing on a `SubscriptionSchedule`.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SchedulePhase {
+    /// Define thresholds at which an invoice will be sent, and the subscription advanced to a
+    /// new billing period, during this phase.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing_thresholds: Option<SubscriptionBillingThresholds>,
+
+    /// A coupon to apply to this phase of the subscription schedule, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coupon: Option<String>,
+
+    /// IDs of the tax rates that will apply to any subscription item that does not have
+    /// `tax_rates` set, for this phase.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_tax_rates: Option<Vec<String>>,
+
+    /// The end of this phase.
+    pub end_date: Timestamp,
+
+    /// Plans and quantities that will be applied to the subscription during this phase.
+    pub plans: Vec<SchedulePhasePlan>,
+
+    /// Controls whether and how proration is applied when transitioning into this phase.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proration_behavior: Option<SchedulePhaseProrationBehavior>,
+
+    /// The start of this phase.
+    pub start_date: Timestamp,
+
+    /// When the trial ends within this phase.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trial_end: Option<Timestamp>,
+}
+
+/// A plan and quantity applied during a `SchedulePhase`.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SchedulePhasePlan {
+    /// ID of the plan to which the customer should be subscribed during this phase.
+    pub plan: String,
+
+    /// Quantity of the plan to which the customer should be subscribed during this phase.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u64>,
+}
+
+/// An enum representing the possible values of a `SubscriptionSchedule`'s `end_behavior` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SubscriptionScheduleEndBehavior {
+    Cancel,
+    None,
+    Release,
+    Renew,
+}
+
+impl std::default::Default for SubscriptionScheduleEndBehavior {
+    fn default() -> Self {
+        Self::Release
+    }
+}
+
+/// An enum representing the possible values of a `SubscriptionSchedule`'s `status` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SubscriptionScheduleStatus {
+    Active,
+    Canceled,
+    Completed,
+    NotStarted,
+    Released,
+}
+
+/// An enum representing the possible values of a `SchedulePhase`'s `proration_behavior` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SchedulePhaseProrationBehavior {
+    AlwaysInvoice,
+    CreateProrations,
+    None,
+}
+
+impl std::default::Default for SchedulePhaseProrationBehavior {
+    fn default() -> Self {
+        Self::CreateProrations
+    }
+}
+
+/// A phase of billing to apply when creating or updating a `SubscriptionSchedule`.
+#[derive(Clone, Debug, Serialize)]
+pub struct SchedulePhaseParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing_thresholds: Option<SubscriptionBillingThresholds>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coupon: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_tax_rates: Option<Vec<&'a str>>,
+
+    pub end_date: Timestamp,
+
+    pub plans: Vec<ItemParams<'a>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proration_behavior: Option<SchedulePhaseProrationBehavior>,
+
+    pub start_date: Timestamp,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trial_end: Option<Timestamp>,
+}
+
+/// The parameters for `SubscriptionSchedule::create`.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CreateSubscriptionSchedule<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<CustomerId>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_settings: Option<SubscriptionScheduleDefaultSettings>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_behavior: Option<SubscriptionScheduleEndBehavior>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phases: Option<Vec<SchedulePhaseParams<'a>>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<Timestamp>,
+}
+
+impl<'a> CreateSubscriptionSchedule<'a> {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+/// The parameters for `SubscriptionSchedule::create_from_subscription`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateSubscriptionScheduleFromSubscription<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// The subscription from which to migrate the billing schedule.
+    ///
+    /// The subscription's current billing cycle phase will be used to seed the new subscription
+    /// schedule.
+    pub from_subscription: &'a SubscriptionId,
+}
+
+impl<'a> CreateSubscriptionScheduleFromSubscription<'a> {
+    pub fn new(from_subscription: &'a SubscriptionId) -> Self {
+        CreateSubscriptionScheduleFromSubscription { expand: Default::default(), from_subscription }
+    }
+}
+
+/// The parameters for `SubscriptionSchedule::update`.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct UpdateSubscriptionSchedule<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_settings: Option<SubscriptionScheduleDefaultSettings>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_behavior: Option<SubscriptionScheduleEndBehavior>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phases: Option<Vec<SchedulePhaseParams<'a>>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proration_behavior: Option<SchedulePhaseProrationBehavior>,
+}
+
+impl<'a> UpdateSubscriptionSchedule<'a> {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+/// The parameters for `SubscriptionSchedule::cancel`.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CancelSubscriptionSchedule {
+    /// If the subscription schedule is `active`, indicates whether or not to generate a final
+    /// invoice that contains any un-invoiced metered usage and new/pending proration invoice
+    /// items.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_now: Option<bool>,
+
+    /// If the subscription schedule is `active`, indicates if the cancellation should be
+    /// prorated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prorate: Option<bool>,
+}
+
+/// The parameters for `SubscriptionSchedule::list`.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ListSubscriptionSchedules<'a> {
+    /// Only return subscription schedules for the given customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<CustomerId>,
+
+    /// A cursor for use in pagination.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a SubscriptionScheduleId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a SubscriptionScheduleId>,
+}
+
+impl<'a> ListSubscriptionSchedules<'a> {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
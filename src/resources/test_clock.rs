@@ -0,0 +1,160 @@
+// ======================================
+// This file was automatically generated.
+// ======================================
+
+use crate::config::{Client, Response};
+use crate::ids::TestClockId;
+use crate::params::{Expand, List, Metadata, Object, Timestamp};
+use serde_derive::{Deserialize, Serialize};
+
+/// The resource representing a Stripe "TestHelpersTestClock".
+///
+/// For more details see [https://stripe.com/docs/api/test_clocks/object](https://stripe.com/docs/api/test_clocks/object).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TestClock {
+    /// Unique identifier for the object.
+    pub id: TestClockId,
+
+    /// Time at which the object was created.
+    ///
+    /// Measured in seconds since the Unix epoch.
+    pub created: Timestamp,
+
+    /// Time at which this test clock is scheduled to expire.
+    pub deletes_after: Timestamp,
+
+    /// Time at which all Stripe objects belonging to this test clock are frozen.
+    pub frozen_time: Timestamp,
+
+    /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+    pub livemode: bool,
+
+    /// The custom name supplied at creation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// The status of the test clock.
+    pub status: TestClockStatus,
+}
+
+impl TestClock {
+    /// Returns a list of your test clocks.
+    pub fn list(client: &Client, params: ListTestClocks<'_>) -> Response<List<TestClock>> {
+        client.get_query("/test_helpers/test_clocks", &params)
+    }
+
+    /// Creates a new test clock that can be attached to new customers and quotes.
+    pub fn create(client: &Client, params: CreateTestClock<'_>) -> Response<TestClock> {
+        client.post_form("/test_helpers/test_clocks", &params)
+    }
+
+    /// Retrieves a test clock.
+    pub fn retrieve(
+        client: &Client,
+        id: &TestClockId,
+        expand: &[&str],
+    ) -> Response<TestClock> {
+        client.get_query(&format!("/test_helpers/test_clocks/{}", id), &Expand { expand })
+    }
+
+    /// Deletes a test clock.
+    pub fn delete(client: &Client, id: &TestClockId) -> Response<TestClock> {
+        client.delete(&format!("/test_helpers/test_clocks/{}", id))
+    }
+
+    /// Starts advancing a test clock to a specified time in the future.
+    ///
+    /// Advancement is done when the status changes from `advancing` to `ready`.
+    pub fn advance(
+        client: &Client,
+        id: &TestClockId,
+        params: AdvanceTestClock,
+    ) -> Response<TestClock> {
+        client.post_form(&format!("/test_helpers/test_clocks/{}/advance", id), &params)
+    }
+}
+
+impl Object for TestClock {
+    type Id = TestClockId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "test_helpers.test_clock"
+    }
+}
+
+/// An enum representing the possible values of a `TestClock`'s `status` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TestClockStatus {
+    Advancing,
+    InternalFailure,
+    Ready,
+}
+
+/// The parameters for `TestClock::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateTestClock<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// The initial frozen time for this test clock.
+    pub frozen_time: Timestamp,
+
+    /// Set of key-value pairs that you can attach to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// The name for this test clock.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<&'a str>,
+}
+
+impl<'a> CreateTestClock<'a> {
+    pub fn new(frozen_time: Timestamp) -> Self {
+        CreateTestClock {
+            expand: Default::default(),
+            frozen_time,
+            metadata: Default::default(),
+            name: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `TestClock::advance`.
+#[derive(Clone, Debug, Serialize)]
+pub struct AdvanceTestClock {
+    /// The time to advance the test clock to.
+    ///
+    /// Must be after the test clock's current frozen time, and cannot be more than two
+    /// intervals in the future from the shortest subscription in this test clock.
+    pub frozen_time: Timestamp,
+}
+
+/// The parameters for `TestClock::list`.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ListTestClocks<'a> {
+    /// A cursor for use in pagination.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a TestClockId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a TestClockId>,
+}
+
+impl<'a> ListTestClocks<'a> {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}